@@ -0,0 +1,99 @@
+use std::{convert::Infallible, fmt::Display};
+
+use snafu::prelude::*;
+use util::aoc::{Parameters, Solution};
+
+pub struct NotQuiteLisp;
+
+#[derive(Debug, PartialEq, Snafu)]
+#[snafu(display("Char '{invalid_char}' at index {index} is not a bracket."))]
+pub struct BracketParseError {
+    invalid_char: char,
+    index: usize,
+}
+
+fn parse_char(index: usize, char: char) -> Result<i32, BracketParseError> {
+    match char {
+        '(' => Ok(1),
+        ')' => Ok(-1),
+        _ => Err(BracketParseError {
+            invalid_char: char,
+            index,
+        }),
+    }
+}
+
+impl Solution for NotQuiteLisp {
+    const YEAR: u16 = 2015;
+    const DAY: u16 = 1;
+
+    type Input = Vec<i32>;
+    type ParseError = BracketParseError;
+    type SolveError = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        input
+            .chars()
+            .enumerate()
+            .map(|(index, char)| parse_char(index, char))
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(input.iter().sum::<i32>())
+    }
+
+    fn part_two(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        let mut floor = 0;
+        let index = input
+            .iter()
+            .position(|step| {
+                floor += step;
+                floor < 0
+            })
+            .expect("At no point did Santa go into the basement.");
+
+        Ok(index + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../../res/2015/01/example.txt");
+
+    #[test]
+    fn parse_test_err() {
+        let expected = BracketParseError {
+            invalid_char: 'x',
+            index: 2,
+        };
+
+        assert_eq!(Err(expected), NotQuiteLisp::parse("()x)"));
+    }
+
+    #[test]
+    fn part_one_test() {
+        let input = NotQuiteLisp::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "-3",
+            NotQuiteLisp::part_one(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = NotQuiteLisp::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "1",
+            NotQuiteLisp::part_two(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+}
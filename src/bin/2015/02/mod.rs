@@ -0,0 +1,156 @@
+use std::{convert::Infallible, fmt::Display, num::ParseIntError};
+
+use itertools::Itertools;
+use snafu::prelude::*;
+use util::aoc::{Parameters, Solution};
+
+pub struct NoMath;
+
+#[derive(Debug, PartialEq, Snafu)]
+#[snafu(display("Could not parse line {line_number} into a present: {source}"))]
+pub struct PresentsParseError {
+    source: PresentParseError,
+    line_number: usize,
+}
+
+#[derive(Debug, PartialEq, Snafu)]
+pub enum PresentParseError {
+    #[snafu(display("String '{string}' could not be parsed to a number: {source}"))]
+    InvalidNumber {
+        source: ParseIntError,
+        string: String,
+    },
+    #[snafu(display("Expected 3 dimensions but found {count}."))]
+    WrongDimensionCount { count: usize },
+}
+
+pub struct RectangularPrism(u32, u32, u32);
+
+impl TryFrom<&[u32]> for RectangularPrism {
+    type Error = PresentParseError;
+
+    fn try_from(value: &[u32]) -> Result<Self, Self::Error> {
+        if let &[l, w, h] = value {
+            Ok(RectangularPrism(l, w, h))
+        } else {
+            Err(PresentParseError::WrongDimensionCount { count: value.len() })
+        }
+    }
+}
+
+impl RectangularPrism {
+    fn wrapping_paper_size(&self) -> u32 {
+        let RectangularPrism(l, w, h) = self;
+        let sides = [l * w, w * h, h * l];
+        let smallest_side = sides.into_iter().min().unwrap();
+
+        sides.into_iter().fold(0u32, |acc, e| acc + 2 * e) + smallest_side
+    }
+
+    fn ribbon_length(&self) -> u32 {
+        let RectangularPrism(l, w, h) = self;
+        let edges = [l, w, h];
+        let smallest_edges = edges.into_iter().sorted().take(2).collect_vec();
+
+        let present = smallest_edges.into_iter().fold(0u32, |acc, e| acc + 2 * e);
+        let bow: u32 = edges.into_iter().product();
+
+        present + bow
+    }
+}
+
+fn parse_line(line: &str) -> Result<RectangularPrism, PresentParseError> {
+    let dimensions: Vec<u32> = line
+        .split('x')
+        .map(|number| {
+            number
+                .parse()
+                .context(InvalidNumberSnafu { string: number })
+        })
+        .try_collect()?;
+    RectangularPrism::try_from(dimensions.as_slice())
+}
+
+impl Solution for NoMath {
+    const YEAR: u16 = 2015;
+    const DAY: u16 = 2;
+
+    type Input = Vec<RectangularPrism>;
+    type ParseError = PresentsParseError;
+    type SolveError = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                parse_line(line).context(PresentsParseSnafu {
+                    line_number: index + 1,
+                })
+            })
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(input
+            .iter()
+            .map(RectangularPrism::wrapping_paper_size)
+            .sum::<u32>())
+    }
+
+    fn part_two(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(input
+            .iter()
+            .map(RectangularPrism::ribbon_length)
+            .sum::<u32>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../../res/2015/02/example.txt");
+
+    #[rstest]
+    #[case("2x3x4\n1x1", PresentParseError::WrongDimensionCount { count: 2 })]
+    #[case("2x3x4\n1x1x1x1", PresentParseError::WrongDimensionCount { count: 4 })]
+    #[case("2x3x4\n1xax1", PresentParseError::InvalidNumber {
+        source: "a".parse::<u32>().unwrap_err(),
+        string: "a".to_string(),
+    })]
+    fn parse_test_err(#[case] input: &str, #[case] source: PresentParseError) {
+        let expected = PresentsParseError {
+            source,
+            line_number: 2,
+        };
+
+        assert_eq!(expected, NoMath::parse(input).err().unwrap());
+    }
+
+    #[test]
+    fn part_one_test() {
+        let input = NoMath::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "58",
+            NoMath::part_one(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = NoMath::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "34",
+            NoMath::part_two(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+}
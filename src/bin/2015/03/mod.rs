@@ -0,0 +1,159 @@
+use std::{collections::HashSet, convert::Infallible, fmt::Display, ops::Add};
+
+use snafu::prelude::*;
+use util::aoc::{Parameters, Solution};
+
+pub struct SphericalHouses;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+struct Position(i32, i32);
+
+impl Add for Position {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+#[derive(Debug, PartialEq, Snafu)]
+#[snafu(display("Char '{invalid_char}' at index {index} is not a direction."))]
+pub struct DirectionParseError {
+    invalid_char: char,
+    index: usize,
+}
+
+#[derive(Clone, Copy)]
+pub enum Direction {
+    Up,
+    Down,
+    Right,
+    Left,
+}
+
+impl Direction {
+    fn parse(index: usize, char: char) -> Result<Self, DirectionParseError> {
+        match char {
+            '^' => Ok(Direction::Up),
+            'v' => Ok(Direction::Down),
+            '>' => Ok(Direction::Right),
+            '<' => Ok(Direction::Left),
+            _ => Err(DirectionParseError {
+                invalid_char: char,
+                index,
+            }),
+        }
+    }
+}
+
+struct Deliverer {
+    current_pos: Position,
+}
+
+impl Deliverer {
+    fn at_start() -> Self {
+        Self {
+            current_pos: Position(0, 0),
+        }
+    }
+
+    fn go_deliver(&mut self, dir: Direction) {
+        self.current_pos = match dir {
+            Direction::Up => Position(0, 1) + self.current_pos,
+            Direction::Down => Position(0, -1) + self.current_pos,
+            Direction::Right => Position(1, 0) + self.current_pos,
+            Direction::Left => Position(-1, 0) + self.current_pos,
+        };
+    }
+}
+
+impl Solution for SphericalHouses {
+    const YEAR: u16 = 2015;
+    const DAY: u16 = 3;
+
+    type Input = Vec<Direction>;
+    type ParseError = DirectionParseError;
+    type SolveError = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        input
+            .chars()
+            .enumerate()
+            .map(|(index, char)| Direction::parse(index, char))
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        let mut santa = Deliverer::at_start();
+
+        Ok(input
+            .iter()
+            .map(|dir| {
+                santa.go_deliver(*dir);
+                santa.current_pos
+            })
+            .collect::<HashSet<_>>()
+            .len())
+    }
+
+    fn part_two(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        let mut santa = Deliverer::at_start();
+        let mut robo_santa = Deliverer::at_start();
+
+        Ok(input
+            .iter()
+            .enumerate()
+            .map(|(index, dir)| {
+                if index % 2 == 0 {
+                    santa.go_deliver(*dir);
+                    santa.current_pos
+                } else {
+                    robo_santa.go_deliver(*dir);
+                    robo_santa.current_pos
+                }
+            })
+            .collect::<HashSet<_>>()
+            .len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../../res/2015/03/example.txt");
+
+    #[test]
+    fn parse_test_err() {
+        let expected = DirectionParseError {
+            invalid_char: 'x',
+            index: 1,
+        };
+
+        assert_eq!(expected, SphericalHouses::parse("^x").err().unwrap());
+    }
+
+    #[test]
+    fn part_one_test() {
+        let input = SphericalHouses::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "4",
+            SphericalHouses::part_one(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = SphericalHouses::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "3",
+            SphericalHouses::part_two(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use std::{convert::Infallible, fmt::Display};
+
+use util::{
+    aoc::{Parameters, Solution},
+    hash_search::{self, Md5Search},
+};
+
+pub struct IdealStockingStuffer;
+
+/// The first positive counter whose hash starts with `zeros` zeros.
+fn mine(key: &str, zeros: usize) -> usize {
+    Md5Search::new(key)
+        .find_first(1, |digest| {
+            hash_search::has_leading_zero_nibbles(digest, zeros)
+        })
+        .expect("No hash could be found that fulfills the requirements.")
+}
+
+impl Solution for IdealStockingStuffer {
+    const YEAR: u16 = 2015;
+    const DAY: u16 = 4;
+
+    type Input = String;
+    type ParseError = Infallible;
+    type SolveError = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part_one(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(mine(input, 5))
+    }
+
+    fn part_two(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(mine(input, 6))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("609043", "abcdef")]
+    #[case("1048970", "pqrstuv")]
+    fn part_one_test(#[case] expected: &str, #[case] input: &str) {
+        let input = IdealStockingStuffer::parse(input).unwrap();
+
+        assert_eq!(
+            expected,
+            IdealStockingStuffer::part_one(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+}
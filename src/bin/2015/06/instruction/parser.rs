@@ -8,7 +8,9 @@ use nom::{
     Finish, IResult,
 };
 
-use crate::instruction::{Command, Coordinate, CoordinatePair, Instruction};
+use util::geometry::Point;
+
+use crate::y2015::d06::instruction::{Command, CoordinatePair, Instruction};
 
 pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, nom::error::Error<&str>> {
     let (_, instructions) = many0(parse_instruction)(input).finish()?;
@@ -39,9 +41,9 @@ fn parse_coordinate_pair(input: &str) -> IResult<&str, CoordinatePair> {
     Ok((input, CoordinatePair(from_coordinate, to_coordinate)))
 }
 
-fn parse_coordinate(input: &str) -> IResult<&str, Coordinate> {
+fn parse_coordinate(input: &str) -> IResult<&str, Point<u16>> {
     let (input, (x, y)) = separated_pair(parse_digit, char(','), parse_digit)(input)?;
-    Ok((input, Coordinate { x, y }))
+    Ok((input, Point::new(x, y)))
 }
 
 fn parse_digit(input: &str) -> IResult<&str, u16> {
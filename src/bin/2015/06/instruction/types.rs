@@ -1,10 +1,14 @@
-#[derive(Debug)]
+use std::ops::Range;
+
+use util::geometry::Point;
+
+#[derive(Debug, Clone, Copy)]
 pub struct Instruction {
     pub command: Command,
     pub coordinate_pair: CoordinatePair,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum Command {
     On,
     Off,
@@ -22,96 +26,19 @@ impl From<&str> for Command {
     }
 }
 
-#[derive(Debug)]
-pub struct CoordinatePair(pub Coordinate, pub Coordinate);
-
-impl CoordinatePair {
-    pub fn area(self) -> CoordinatePairArea {
-        CoordinatePairArea::new(self)
-    }
-}
-
 #[derive(Debug, Clone, Copy)]
-pub struct Coordinate {
-    pub x: u16,
-    pub y: u16,
-}
-
-pub struct CoordinatePairArea {
-    coordinate_pair: CoordinatePair,
-    current: Option<Coordinate>,
-    is_first: bool,
-}
-
-impl CoordinatePairArea {
-    fn new(coordinate_pair: CoordinatePair) -> Self {
-        let CoordinatePair(first, _) = coordinate_pair;
-        CoordinatePairArea {
-            coordinate_pair,
-            current: Some(first),
-            is_first: true,
-        }
-    }
-
-    fn update_current_coordinate(&mut self) {
-        if self.current.is_some() {
-            if self.is_end_of_row() {
-                self.start_at_next_row();
-            } else {
-                self.move_right();
-            }
+pub struct CoordinatePair(pub Point<u16>, pub Point<u16>);
 
-            if self.is_done() {
-                self.current = None;
-            }
-        }
-    }
-
-    fn is_end_of_row(&self) -> bool {
-        let CoordinatePair(_, highest) = self.coordinate_pair;
-        let last = self.safely_unwrap_current();
-        last.x >= highest.x
-    }
-
-    fn start_at_next_row(&mut self) {
-        let CoordinatePair(lowest, _) = self.coordinate_pair;
-        let last = self.safely_unwrap_current();
-        self.current = Some(Coordinate {
-            x: lowest.x,
-            y: last.y + 1,
-        });
-    }
-
-    fn move_right(&mut self) {
-        let last = self.safely_unwrap_current();
-        self.current = Some(Coordinate {
-            x: last.x + 1,
-            y: last.y,
-        })
-    }
-
-    fn safely_unwrap_current(&self) -> Coordinate {
-        self.current.expect("Current element should not be `None`.")
-    }
-
-    fn is_done(&self) -> bool {
-        let CoordinatePair(_, highest) = self.coordinate_pair;
-        match self.current {
-            Some(current) => current.y > highest.y,
-            None => true,
-        }
+impl CoordinatePair {
+    /// The columns of the inclusive rectangle spanned by the pair, as a half-open range.
+    pub fn x_range(self) -> Range<usize> {
+        let CoordinatePair(lowest, highest) = self;
+        lowest.x as usize..highest.x as usize + 1
     }
-}
 
-impl Iterator for CoordinatePairArea {
-    type Item = Coordinate;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.is_first {
-            self.is_first = false;
-        } else {
-            self.update_current_coordinate();
-        }
-        self.current
+    /// The rows of the inclusive rectangle spanned by the pair, as a half-open range.
+    pub fn y_range(self) -> Range<usize> {
+        let CoordinatePair(lowest, highest) = self;
+        lowest.y as usize..highest.y as usize + 1
     }
 }
@@ -0,0 +1,158 @@
+use std::ops::Range;
+
+use util::grid::{Grid, Position};
+
+use crate::y2015::d06::instruction::{CoordinatePair, Instruction};
+
+/// Lights grouped into rectangles by the edges of all instructions' rectangles. Every
+/// instruction covers either all or none of the lights of such a rectangle, so they always share
+/// the same state and only one value per rectangle is stored.
+pub struct CompressedLightGrid<T> {
+    column_edges: Vec<usize>,
+    row_edges: Vec<usize>,
+    rectangles: Grid<T>,
+}
+
+impl<T: Clone + Default> CompressedLightGrid<T> {
+    pub fn new(instructions: &[Instruction]) -> Self {
+        let column_edges = edges(instructions.iter().map(|i| i.coordinate_pair.x_range()));
+        let row_edges = edges(instructions.iter().map(|i| i.coordinate_pair.y_range()));
+        let rectangles = Grid::filled(
+            column_edges.len().saturating_sub(1),
+            row_edges.len().saturating_sub(1),
+            T::default(),
+        );
+
+        Self {
+            column_edges,
+            row_edges,
+            rectangles,
+        }
+    }
+
+    /// Applies `update` once to every rectangle covered by `coordinate_pair`.
+    pub fn update(&mut self, coordinate_pair: CoordinatePair, update: impl Fn(&mut T)) {
+        let columns = compress(&self.column_edges, coordinate_pair.x_range());
+        let rows = compress(&self.row_edges, coordinate_pair.y_range());
+        let mut area = self
+            .rectangles
+            .view_mut(
+                Position::new(columns.start, rows.start),
+                columns.len(),
+                rows.len(),
+            )
+            .unwrap_or_else(|| panic!("Coordinate pair `{:?}` is out of bounds.", coordinate_pair));
+        for rectangle in area.rows_mut().flatten() {
+            update(rectangle);
+        }
+    }
+
+    /// Sums up `value` over every single light.
+    pub fn sum(&self, value: impl Fn(&T) -> usize) -> usize {
+        self.rectangles
+            .iter()
+            .map(|(position, rectangle)| value(rectangle) * self.light_count(position))
+            .sum()
+    }
+
+    /// One value per light, from `(0, 0)` to the furthest edges.
+    pub fn expand(&self) -> Grid<T> {
+        let rectangle_indices = |edges: &[usize]| {
+            let mut indices = vec![None; edges.last().copied().unwrap_or(0)];
+            for (index, window) in edges.windows(2).enumerate() {
+                indices[window[0]..window[1]].fill(Some(index));
+            }
+            indices
+        };
+        let columns = rectangle_indices(&self.column_edges);
+        let rows = rectangle_indices(&self.row_edges);
+
+        Grid::from_fn(columns.len(), rows.len(), |position| {
+            match (columns[position.x], rows[position.y]) {
+                (Some(x), Some(y)) => self.rectangles[Position::new(x, y)].clone(),
+                _ => T::default(),
+            }
+        })
+    }
+
+    fn light_count(&self, position: Position) -> usize {
+        let width = self.column_edges[position.x + 1] - self.column_edges[position.x];
+        let height = self.row_edges[position.y + 1] - self.row_edges[position.y];
+        width * height
+    }
+}
+
+fn edges(ranges: impl Iterator<Item = Range<usize>>) -> Vec<usize> {
+    let mut edges = ranges
+        .flat_map(|range| [range.start, range.end])
+        .collect::<Vec<_>>();
+    edges.sort_unstable();
+    edges.dedup();
+    edges
+}
+
+/// Translates a range of lights into the range of rectangles between the same edges.
+fn compress(edges: &[usize], range: Range<usize>) -> Range<usize> {
+    let index = |edge| {
+        edges
+            .binary_search(&edge)
+            .unwrap_or_else(|_| panic!("Edge {} is not known.", edge))
+    };
+    index(range.start)..index(range.end)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use util::geometry::Point;
+
+    use super::*;
+    use crate::y2015::d06::instruction::Command;
+
+    fn instruction(command: Command, from: (u16, u16), to: (u16, u16)) -> Instruction {
+        Instruction {
+            command,
+            coordinate_pair: CoordinatePair(Point::new(from.0, from.1), Point::new(to.0, to.1)),
+        }
+    }
+
+    #[test]
+    fn edges_test() {
+        let ranges = [3..5, 0..3, 4..10];
+
+        assert_eq!(vec![0, 3, 4, 5, 10], edges(ranges.into_iter()));
+    }
+
+    #[test]
+    fn compress_test() {
+        let edges = [0, 3, 4, 5, 10];
+
+        assert_eq!(1..4, compress(&edges, 3..10));
+        assert_eq!(0..1, compress(&edges, 0..3));
+    }
+
+    #[test]
+    fn compressed_light_grid_test() {
+        let instructions = [
+            instruction(Command::On, (0, 0), (2, 2)),
+            instruction(Command::Toggle, (1, 1), (4, 1)),
+        ];
+        let mut grid = CompressedLightGrid::<u8>::new(&instructions);
+        for instruction in instructions {
+            grid.update(instruction.coordinate_pair, |light| *light += 1);
+        }
+
+        assert_eq!((3, 3), (grid.rectangles.width(), grid.rectangles.height()));
+        assert_eq!(9 + 4, grid.sum(|light| *light as usize));
+        assert_eq!(9 + 2, grid.sum(|light| (*light > 0) as usize));
+        assert_eq!("11100\n12211\n11100", grid.expand().to_string());
+    }
+
+    #[test]
+    fn compressed_light_grid_test_empty() {
+        let grid = CompressedLightGrid::<bool>::new(&[]);
+
+        assert_eq!(0, grid.sum(|light| *light as usize));
+        assert_eq!((0, 0), (grid.expand().width(), grid.expand().height()));
+    }
+}
@@ -1,8 +1,20 @@
+use util::image::Image;
+
+use crate::y2015::d06::instruction::Instruction;
+
+mod compressed;
 mod fixed_lumination;
 mod variable_lumination;
 
+use compressed::CompressedLightGrid;
 pub use fixed_lumination::FixedLuminationLightGrid;
 pub use variable_lumination::VariableLuminationLightGrid;
 
-pub const GRID_DIMENSION_SIZE: usize = 1_000;
-pub const GRID_SIZE: usize = GRID_DIMENSION_SIZE * GRID_DIMENSION_SIZE;
+pub trait LightGrid {
+    /// All lights turned off, grouped by the rectangles of `instructions`.
+    fn turned_off(instructions: &[Instruction]) -> Self;
+    fn execute_instruction(&mut self, instruction: Instruction);
+    fn count_turned_on_lights(&self) -> usize;
+    /// Every light from the top left corner to the furthest one an instruction touches.
+    fn image(&self) -> Image;
+}
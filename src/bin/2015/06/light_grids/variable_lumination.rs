@@ -1,32 +1,35 @@
-use super::{GRID_DIMENSION_SIZE, GRID_SIZE};
-use crate::instruction::{Command, Coordinate, Instruction};
+use util::image::Image;
 
-pub struct VariableLuminationLightGrid([u8; GRID_SIZE]);
+use super::{CompressedLightGrid, LightGrid};
+use crate::y2015::d06::instruction::{Command, Instruction};
 
-impl VariableLuminationLightGrid {
-    pub fn turned_off() -> Self {
-        Self([0; GRID_SIZE])
+pub struct VariableLuminationLightGrid(CompressedLightGrid<u32>);
+
+impl LightGrid for VariableLuminationLightGrid {
+    fn turned_off(instructions: &[Instruction]) -> Self {
+        Self(CompressedLightGrid::new(instructions))
     }
 
-    pub fn execute_instruction(&mut self, instruction: Instruction) {
-        for current_coordinate in instruction.coordinate_pair.area() {
-            let light = self.get_mut(&current_coordinate);
+    fn execute_instruction(&mut self, instruction: Instruction) {
+        self.0.update(instruction.coordinate_pair, |light| {
             match instruction.command {
-                Command::On => *light += 1,
+                Command::On => *light = light.saturating_add(1),
                 Command::Off => *light = light.saturating_sub(1),
-                Command::Toggle => *light += 2,
+                Command::Toggle => *light = light.saturating_add(2),
             }
-        }
+        });
     }
 
-    pub fn count_turned_on_lights(&self) -> usize {
-        self.0.iter().fold(0usize, |acc, e| acc + *e as usize)
+    fn count_turned_on_lights(&self) -> usize {
+        self.0.sum(|light| *light as usize)
     }
 
-    fn get_mut(&mut self, coordinate: &Coordinate) -> &mut u8 {
-        let index = coordinate.y as usize * GRID_DIMENSION_SIZE + coordinate.x as usize;
-        self.0
-            .get_mut(index)
-            .unwrap_or_else(|| panic!("Coordinate `{:?}` is out of bounds.", coordinate))
+    fn image(&self) -> Image {
+        Image::grayscale(
+            &self
+                .0
+                .expand()
+                .map(|light| u16::try_from(*light).unwrap_or(u16::MAX)),
+        )
     }
 }
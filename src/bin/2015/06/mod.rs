@@ -0,0 +1,124 @@
+mod instruction;
+mod light_grids;
+
+use std::{convert::Infallible, fmt::Display, io};
+
+use instruction::Instruction;
+use light_grids::{FixedLuminationLightGrid, LightGrid, VariableLuminationLightGrid};
+use util::{
+    aoc::{Parameters, Solution},
+    image::ImageExport,
+};
+
+pub struct FireHazard;
+
+fn execute_instructions<G: LightGrid>(instructions: &[Instruction]) -> usize {
+    let mut grid = G::turned_off(instructions);
+    for instruction in instructions {
+        grid.execute_instruction(*instruction);
+    }
+    grid.count_turned_on_lights()
+}
+
+/// Exports the final lights as `<name>` and, if requested, every intermediate state as frames.
+fn export<G: LightGrid>(
+    instructions: &[Instruction],
+    export: &ImageExport,
+    name: &str,
+) -> io::Result<()> {
+    let mut frames = export.frames(name)?;
+    let mut grid = G::turned_off(instructions);
+    for instruction in instructions {
+        grid.execute_instruction(*instruction);
+        if let Some(frames) = &mut frames {
+            frames.push(&grid.image())?;
+        }
+    }
+    export.save(name, &grid.image())?;
+    Ok(())
+}
+
+impl Solution for FireHazard {
+    const YEAR: u16 = 2015;
+    const DAY: u16 = 6;
+
+    type Input = Vec<Instruction>;
+    type ParseError = nom::error::Error<String>;
+    type SolveError = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        instruction::parse_instructions(input)
+            .map_err(|error| nom::error::Error::new(error.input.to_string(), error.code))
+    }
+
+    fn part_one(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(execute_instructions::<FixedLuminationLightGrid>(input))
+    }
+
+    fn part_two(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(execute_instructions::<VariableLuminationLightGrid>(input))
+    }
+
+    fn export_images(input: &Self::Input, image_export: &ImageExport) -> io::Result<()> {
+        export::<FixedLuminationLightGrid>(input, image_export, "fixed_lumination")?;
+        export::<VariableLuminationLightGrid>(input, image_export, "variable_lumination")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../../res/2015/06/example.txt");
+
+    #[test]
+    fn part_one_test() {
+        let input = FireHazard::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "998996",
+            FireHazard::part_one(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = FireHazard::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "1001996",
+            FireHazard::part_two(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part_one_test_beyond_thousand() {
+        let input = FireHazard::parse(
+            "turn on 0,0 through 1999,1999\ntoggle 1000,1000 through 2999,1999\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            "4000000",
+            FireHazard::part_one(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part_two_test_brighter_than_u8() {
+        let input = FireHazard::parse(&"toggle 0,0 through 1,0\n".repeat(130)).unwrap();
+
+        assert_eq!(
+            "520",
+            FireHazard::part_two(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+}
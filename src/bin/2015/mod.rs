@@ -0,0 +1,20 @@
+#[path = "01/mod.rs"]
+mod d01;
+#[path = "02/mod.rs"]
+mod d02;
+#[path = "03/mod.rs"]
+mod d03;
+#[path = "04/mod.rs"]
+mod d04;
+#[path = "06/mod.rs"]
+mod d06;
+
+use util::aoc::Registry;
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<d01::NotQuiteLisp>();
+    registry.register::<d02::NoMath>();
+    registry.register::<d03::SphericalHouses>();
+    registry.register::<d04::IdealStockingStuffer>();
+    registry.register::<d06::FireHazard>();
+}
@@ -1,4 +1,16 @@
-use std::str::FromStr;
+use std::{num::ParseIntError, str::FromStr};
+
+use snafu::prelude::*;
+
+#[derive(Debug, PartialEq, Snafu)]
+pub enum InstructionParseError {
+    #[snafu(display("The instruction is empty."))]
+    Empty,
+    #[snafu(display("Char '{invalid_char}' is not a turn direction."))]
+    InvalidDirection { invalid_char: char },
+    #[snafu(display("Could not parse the steps: {source}"))]
+    InvalidSteps { source: ParseIntError },
+}
 
 #[derive(Debug, PartialEq)]
 pub enum Instruction {
@@ -10,21 +22,24 @@ pub enum Instruction {
 pub struct Steps(pub usize);
 
 impl FromStr for Instruction {
-    type Err = ();
+    type Err = InstructionParseError;
 
     fn from_str(string: &str) -> Result<Self, Self::Err> {
         let mut string_iter = string.chars();
 
-        let first_char = string_iter.next().ok_or(())?;
+        let first_char = string_iter.next().context(EmptySnafu)?;
         let steps = string_iter
-            .collect::<String>()
+            .as_str()
             .parse::<usize>()
-            .map_err(|_| ())?;
+            .context(InvalidStepsSnafu)?;
 
         match first_char {
             'L' => Ok(Self::Left(Steps(steps))),
             'R' => Ok(Self::Right(Steps(steps))),
-            _ => Err(()),
+            _ => InvalidDirectionSnafu {
+                invalid_char: first_char,
+            }
+            .fail(),
         }
     }
 }
@@ -45,8 +60,14 @@ mod tests {
     #[test]
     fn instruction_trait_from_str_test_err_direction() {
         let invalid_direction = "*12";
+        let expected = InstructionParseError::InvalidDirection { invalid_char: '*' };
 
-        assert_eq!(Err(()), invalid_direction.parse::<Instruction>());
+        assert_eq!(Err(expected), invalid_direction.parse::<Instruction>());
+    }
+
+    #[test]
+    fn instruction_trait_from_str_test_err_empty() {
+        assert_eq!(Err(InstructionParseError::Empty), "".parse::<Instruction>());
     }
 
     #[test]
@@ -55,8 +76,17 @@ mod tests {
         let gibberish_steps = "L_*!";
         let no_steps = "L";
 
-        assert_eq!(Err(()), negative_steps.parse::<Instruction>());
-        assert_eq!(Err(()), gibberish_steps.parse::<Instruction>());
-        assert_eq!(Err(()), no_steps.parse::<Instruction>());
+        assert!(matches!(
+            negative_steps.parse::<Instruction>(),
+            Err(InstructionParseError::InvalidSteps { .. })
+        ));
+        assert!(matches!(
+            gibberish_steps.parse::<Instruction>(),
+            Err(InstructionParseError::InvalidSteps { .. })
+        ));
+        assert!(matches!(
+            no_steps.parse::<Instruction>(),
+            Err(InstructionParseError::InvalidSteps { .. })
+        ));
     }
 }
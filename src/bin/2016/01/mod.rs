@@ -0,0 +1,131 @@
+mod instruction;
+mod player;
+
+use std::fmt::Display;
+
+use instruction::{Instruction, InstructionParseError};
+use player::{PathFollowingPlayer, PathRememberingPlayer};
+use snafu::prelude::*;
+use util::aoc::{Parameters, Solution};
+
+pub struct NoTimeForATaxicab;
+
+#[derive(Debug, PartialEq, Snafu)]
+#[snafu(display("String \"{string}\" could not be parsed to `Instruction`: {source}"))]
+pub struct InstructionsParseError {
+    source: InstructionParseError,
+    string: String,
+}
+
+#[derive(Debug, PartialEq, Snafu)]
+#[snafu(display("Not one position was visited twice."))]
+pub struct NoPositionVisitedTwiceError;
+
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>, InstructionsParseError> {
+    input
+        .split(", ")
+        .map(|string| string.parse().context(InstructionsParseSnafu { string }))
+        .collect()
+}
+
+impl Solution for NoTimeForATaxicab {
+    const YEAR: u16 = 2016;
+    const DAY: u16 = 1;
+
+    type Input = Vec<Instruction>;
+    type ParseError = InstructionsParseError;
+    type SolveError = NoPositionVisitedTwiceError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        parse_instructions(input.trim_end())
+    }
+
+    fn part_one(
+        instructions: &Self::Input,
+        _: &Parameters,
+    ) -> Result<impl Display, Self::SolveError> {
+        let mut player = PathFollowingPlayer::at_start();
+        for instruction in instructions {
+            player.follow_path(instruction);
+        }
+        Ok(player.distance_from_start())
+    }
+
+    fn part_two(
+        instructions: &Self::Input,
+        _: &Parameters,
+    ) -> Result<impl Display, Self::SolveError> {
+        let mut player = PathRememberingPlayer::at_start();
+        for instruction in instructions {
+            player.find_first_position_visited_twice(instruction);
+            if player.has_visited_position_twice() {
+                return Ok(player.distance_from_start());
+            }
+        }
+        NoPositionVisitedTwiceSnafu.fail()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2016::d01::instruction::Steps;
+
+    use super::*;
+
+    #[test]
+    fn parse_instructions_test_success() {
+        let input = "L12, R34";
+        let expected = vec![Instruction::Left(Steps(12)), Instruction::Right(Steps(34))];
+
+        assert_eq!(Ok(expected), parse_instructions(input));
+    }
+
+    #[test]
+    fn parse_instructions_test_failure() {
+        let input = "L12_R34";
+        let expected = InstructionsParseError {
+            source: "L12_R34".parse::<Instruction>().unwrap_err(),
+            string: input.to_string(),
+        };
+
+        assert_eq!(Err(expected), parse_instructions(input));
+    }
+
+    #[test]
+    fn part_one_test() {
+        let input = "R1, L2, R3, R4, R5";
+        assert_eq!(
+            "3",
+            NoTimeForATaxicab::part_one(&parse_instructions(input).unwrap(), &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+
+        let input = "L1, R2, L3, L4, L5";
+        assert_eq!(
+            "3",
+            NoTimeForATaxicab::part_one(&parse_instructions(input).unwrap(), &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = "R8, R4, R4, R8";
+        assert_eq!(
+            "4",
+            NoTimeForATaxicab::part_two(&parse_instructions(input).unwrap(), &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+
+        let input = "L8, L4, L4, L8";
+        assert_eq!(
+            "4",
+            NoTimeForATaxicab::part_two(&parse_instructions(input).unwrap(), &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+}
@@ -4,12 +4,12 @@ mod path_remembering_player;
 pub use path_following_player::*;
 pub use path_remembering_player::*;
 
-use crate::point::Point2D;
+use util::geometry::Point;
 
-const START: Point2D = Point2D::from_cartesian(0, 0);
+const START: Point<isize> = Point::new(0, 0);
 
-fn distance_from_start(position: &Point2D) -> usize {
-    position.manhattan_distance_to(START)
+fn distance_from_start(position: &Point<isize>) -> usize {
+    position.manhattan(START)
 }
 
 #[cfg(test)]
@@ -18,7 +18,7 @@ mod tests {
 
     #[test]
     fn path_following_player_distance_from_start_test() {
-        let position = Point2D::from_cartesian(5, -10);
+        let position = Point::new(5, -10);
 
         assert_eq!(15, distance_from_start(&position));
     }
@@ -1,13 +1,13 @@
-use crate::{
-    direction::Direction,
+use util::geometry::{Direction, Point};
+
+use crate::y2016::d01::{
     instruction::{Instruction, Steps},
     player,
-    point::Point2D,
 };
 
 #[derive(Debug, PartialEq)]
 pub struct PathFollowingPlayer {
-    position: Point2D,
+    position: Point<isize>,
     direction: Direction,
 }
 
@@ -26,24 +26,18 @@ impl PathFollowingPlayer {
     pub fn follow_path(&mut self, instruction: &Instruction) {
         match instruction {
             Instruction::Left(steps) => {
-                self.direction.turn_left();
+                self.direction = self.direction.turn_left();
                 self.walk(*steps);
             }
             Instruction::Right(steps) => {
-                self.direction.turn_right();
+                self.direction = self.direction.turn_right();
                 self.walk(*steps);
             }
         };
     }
 
     fn walk(&mut self, steps: Steps) {
-        let steps = steps.0 as isize;
-        match self.direction {
-            Direction::North => self.position.y += steps,
-            Direction::East => self.position.x += steps,
-            Direction::South => self.position.y -= steps,
-            Direction::West => self.position.x -= steps,
-        };
+        self.position += self.direction.vector() * steps.0 as isize;
     }
 }
 
@@ -54,7 +48,7 @@ mod tests {
     #[test]
     fn path_following_player_at_start_test() {
         let expected = PathFollowingPlayer {
-            position: Point2D { x: 0, y: 0 },
+            position: Point::new(0, 0),
             direction: Direction::North,
         };
 
@@ -66,7 +60,7 @@ mod tests {
         let mut player = PathFollowingPlayer::at_start();
         let instruction = Instruction::Left(Steps(10));
         let expected = PathFollowingPlayer {
-            position: Point2D { x: -10, y: 0 },
+            position: Point::new(-10, 0),
             direction: Direction::West,
         };
 
@@ -80,7 +74,7 @@ mod tests {
         let mut player = PathFollowingPlayer::at_start();
         let instruction = Instruction::Right(Steps(10));
         let expected = PathFollowingPlayer {
-            position: Point2D { x: 10, y: 0 },
+            position: Point::new(10, 0),
             direction: Direction::East,
         };
 
@@ -95,18 +89,18 @@ mod tests {
 
         player.direction = Direction::North;
         player.walk(Steps(5));
-        assert_eq!(Point2D::from_cartesian(0, 5), player.position);
+        assert_eq!(Point::new(0, 5), player.position);
 
         player.direction = Direction::East;
         player.walk(Steps(5));
-        assert_eq!(Point2D::from_cartesian(5, 5), player.position);
+        assert_eq!(Point::new(5, 5), player.position);
 
         player.direction = Direction::South;
         player.walk(Steps(5));
-        assert_eq!(Point2D::from_cartesian(5, 0), player.position);
+        assert_eq!(Point::new(5, 0), player.position);
 
         player.direction = Direction::West;
         player.walk(Steps(5));
-        assert_eq!(Point2D::from_cartesian(0, 0), player.position);
+        assert_eq!(Point::new(0, 0), player.position);
     }
 }
@@ -1,17 +1,17 @@
-use crate::{
-    direction::Direction,
+use util::geometry::{Direction, Point, Vector};
+
+use crate::y2016::d01::{
     instruction::{Instruction, Steps},
     player,
-    point::Point2D,
 };
 
 use std::collections::HashSet;
 
 #[derive(Debug, PartialEq)]
 pub struct PathRememberingPlayer {
-    position: Point2D,
+    position: Point<isize>,
     direction: Direction,
-    visited_positions: HashSet<Point2D>,
+    visited_positions: HashSet<Point<isize>>,
     has_visited_position_twice: bool,
 }
 
@@ -40,28 +40,23 @@ impl PathRememberingPlayer {
 
         match instruction {
             Instruction::Left(steps) => {
-                self.direction.turn_left();
+                self.direction = self.direction.turn_left();
                 self.walk(*steps);
             }
             Instruction::Right(steps) => {
-                self.direction.turn_right();
+                self.direction = self.direction.turn_right();
                 self.walk(*steps);
             }
         };
     }
 
     fn walk(&mut self, steps: Steps) {
-        match self.direction {
-            Direction::North => self.remember_visited_positions(steps, |position| position.y += 1),
-            Direction::East => self.remember_visited_positions(steps, |position| position.x += 1),
-            Direction::South => self.remember_visited_positions(steps, |position| position.y -= 1),
-            Direction::West => self.remember_visited_positions(steps, |position| position.x -= 1),
-        };
+        self.remember_visited_positions(steps, self.direction.vector());
     }
 
-    fn remember_visited_positions(&mut self, steps: Steps, step_update: impl Fn(&mut Point2D)) {
+    fn remember_visited_positions(&mut self, steps: Steps, step: Vector<isize>) {
         for _ in 0..steps.0 {
-            step_update(&mut self.position);
+            self.position += step;
             let not_contained_yet = self.visited_positions.insert(self.position);
             if !not_contained_yet {
                 self.has_visited_position_twice = true;
@@ -80,7 +75,7 @@ mod tests {
     #[test]
     fn path_remembering_player_at_start_test() {
         let expected = PathRememberingPlayer {
-            position: Point2D { x: 0, y: 0 },
+            position: Point::new(0, 0),
             direction: Direction::North,
             visited_positions: HashSet::from([player::START]),
             has_visited_position_twice: false,
@@ -94,13 +89,13 @@ mod tests {
         let mut player = PathRememberingPlayer::at_start();
         let instruction = Instruction::Left(Steps(3));
         let expected = PathRememberingPlayer {
-            position: Point2D { x: -3, y: 0 },
+            position: Point::new(-3, 0),
             direction: Direction::West,
             visited_positions: HashSet::from_iter([
                 player::START,
-                Point2D::from_cartesian(-1, 0),
-                Point2D::from_cartesian(-2, 0),
-                Point2D::from_cartesian(-3, 0),
+                Point::new(-1, 0),
+                Point::new(-2, 0),
+                Point::new(-3, 0),
             ]),
             has_visited_position_twice: false,
         };
@@ -115,13 +110,13 @@ mod tests {
         let mut player = PathRememberingPlayer::at_start();
         let instruction = Instruction::Right(Steps(3));
         let expected = PathRememberingPlayer {
-            position: Point2D { x: 3, y: 0 },
+            position: Point::new(3, 0),
             direction: Direction::East,
             visited_positions: HashSet::from_iter([
                 player::START,
-                Point2D::from_cartesian(1, 0),
-                Point2D::from_cartesian(2, 0),
-                Point2D::from_cartesian(3, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(3, 0),
             ]),
             has_visited_position_twice: false,
         };
@@ -151,32 +146,28 @@ mod tests {
         let mut player = PathRememberingPlayer::at_start();
         let mut expected_visited_positions = HashSet::from([player::START]);
 
-        expected_visited_positions
-            .extend([Point2D::from_cartesian(0, 1), Point2D::from_cartesian(0, 2)]);
+        expected_visited_positions.extend([Point::new(0, 1), Point::new(0, 2)]);
         player.direction = Direction::North;
         player.walk(Steps(2));
-        assert_eq!(Point2D::from_cartesian(0, 2), player.position);
+        assert_eq!(Point::new(0, 2), player.position);
         assert_eq!(expected_visited_positions, player.visited_positions);
 
-        expected_visited_positions
-            .extend([Point2D::from_cartesian(1, 2), Point2D::from_cartesian(2, 2)]);
+        expected_visited_positions.extend([Point::new(1, 2), Point::new(2, 2)]);
         player.direction = Direction::East;
         player.walk(Steps(2));
-        assert_eq!(Point2D::from_cartesian(2, 2), player.position);
+        assert_eq!(Point::new(2, 2), player.position);
         assert_eq!(expected_visited_positions, player.visited_positions);
 
-        expected_visited_positions
-            .extend([Point2D::from_cartesian(2, 1), Point2D::from_cartesian(2, 0)]);
+        expected_visited_positions.extend([Point::new(2, 1), Point::new(2, 0)]);
         player.direction = Direction::South;
         player.walk(Steps(2));
-        assert_eq!(Point2D::from_cartesian(2, 0), player.position);
+        assert_eq!(Point::new(2, 0), player.position);
         assert_eq!(expected_visited_positions, player.visited_positions);
 
-        expected_visited_positions
-            .extend([Point2D::from_cartesian(1, 0), Point2D::from_cartesian(0, 0)]);
+        expected_visited_positions.extend([Point::new(1, 0), Point::new(0, 0)]);
         player.direction = Direction::West;
         player.walk(Steps(2));
-        assert_eq!(Point2D::from_cartesian(0, 0), player.position);
+        assert_eq!(Point::new(0, 0), player.position);
         assert_eq!(expected_visited_positions, player.visited_positions);
     }
 
@@ -184,17 +175,13 @@ mod tests {
     fn path_remembering_player_remember_visited_positions_test_no_duplicate_position() {
         let mut player = PathRememberingPlayer::at_start();
         let expected = PathRememberingPlayer {
-            position: Point2D { x: 0, y: 2 },
+            position: Point::new(0, 2),
             direction: Direction::North,
-            visited_positions: HashSet::from([
-                player::START,
-                Point2D::from_cartesian(0, 1),
-                Point2D::from_cartesian(0, 2),
-            ]),
+            visited_positions: HashSet::from([player::START, Point::new(0, 1), Point::new(0, 2)]),
             has_visited_position_twice: false,
         };
 
-        player.remember_visited_positions(Steps(2), |player_position| player_position.y += 1);
+        player.remember_visited_positions(Steps(2), Direction::North.vector());
 
         assert_eq!(expected, player);
     }
@@ -203,17 +190,17 @@ mod tests {
     fn path_remembering_player_remember_visited_positions_test_duplicate_position() {
         let visited_positions = HashSet::from([
             player::START,
-            Point2D::from_cartesian(0, 1),
-            Point2D::from_cartesian(0, 2),
-            Point2D::from_cartesian(0, 3),
+            Point::new(0, 1),
+            Point::new(0, 2),
+            Point::new(0, 3),
         ]);
         let mut player = PathRememberingPlayer::at_start();
-        player.position = Point2D::from_cartesian(0, 3);
+        player.position = Point::new(0, 3);
         player.visited_positions.extend(&visited_positions);
 
-        player.remember_visited_positions(Steps(10), |player_position| player_position.y -= 1);
+        player.remember_visited_positions(Steps(10), Direction::South.vector());
 
-        assert_eq!(Point2D::from_cartesian(0, 2), player.position);
+        assert_eq!(Point::new(0, 2), player.position);
         assert_eq!(true, player.has_visited_position_twice);
     }
 }
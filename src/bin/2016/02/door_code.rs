@@ -1,9 +1,14 @@
 pub mod diffuse_keypad;
 pub mod normal_keypad;
 
-use std::{collections::HashMap, ops::Add};
+use std::convert::Infallible;
 
-use crate::instruction::Instruction;
+use util::{
+    geometry::{Point, Vector},
+    grid::{Grid, Position},
+};
+
+use crate::y2016::d02::instruction::Instruction;
 
 pub trait Button {
     fn button_number(&self) -> ButtonNumber;
@@ -13,27 +18,38 @@ pub trait Button {
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ButtonNumber(pub char);
 
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
-pub struct ButtonLocation {
-    x: i8,
-    y: i8,
-}
-
-impl ButtonLocation {
-    pub const fn at(x: i8, y: i8) -> Self {
-        Self { x, y }
+/// The location of a button relative to the center of its keypad, with y pointing up.
+pub type ButtonLocation = Point<i8>;
+
+/// The buttons of a keypad by their location.
+pub struct Keypad(Grid<Option<ButtonNumber>>);
+
+impl Keypad {
+    /// Parses one row of buttons per line, with a space where there is no button. The center of
+    /// the layout is at [`ButtonLocation::default`].
+    pub fn new(layout: &str) -> Self {
+        let grid = Grid::parse(layout, |_, char| {
+            Ok::<_, Infallible>((char != ' ').then_some(ButtonNumber(char)))
+        })
+        .expect("Keypad layout should be rectangular.");
+        Self(grid)
     }
 
-    pub fn button_number(&self, keypad_layout: HashMap<Self, ButtonNumber>) -> ButtonNumber {
-        keypad_layout[self]
+    pub fn button_number(&self, location: ButtonLocation) -> ButtonNumber {
+        self.get(location)
+            .unwrap_or_else(|| panic!("There is no button at `{:?}`.", location))
     }
-}
 
-impl Add for ButtonLocation {
-    type Output = ButtonLocation;
+    pub fn contains(&self, location: ButtonLocation) -> bool {
+        self.get(location).is_some()
+    }
 
-    fn add(self, rhs: Self) -> Self::Output {
-        Self::Output::at(self.x + rhs.x, self.y + rhs.y)
+    fn get(&self, location: ButtonLocation) -> Option<ButtonNumber> {
+        let Keypad(grid) = self;
+        let center = Position::new(grid.width() / 2, grid.height() / 2);
+        let position =
+            center.checked_shift(Vector::new(location.x as isize, -location.y as isize))?;
+        *grid.get(position)?
     }
 }
 
@@ -50,66 +66,37 @@ fn follow_instruction(
     }
 }
 
-fn is_in_3_times_3_grid(location: ButtonLocation) -> bool {
-    let valid_range = -1..=1;
-    valid_range.contains(&location.x) && valid_range.contains(&location.y)
-}
-
 #[cfg(test)]
 mod tests {
     use rstest::{fixture, rstest};
 
     use super::*;
 
-    #[test]
-    fn button_location_at_test() {
-        assert_eq!(
-            ButtonLocation { x: 10, y: -10 },
-            ButtonLocation::at(10, -10)
-        );
-    }
-
     #[fixture]
-    fn keypad_layout() -> Vec<(ButtonLocation, ButtonNumber)> {
-        vec![(ButtonLocation::at(0, 0), ButtonNumber('0'))]
+    fn keypad() -> Keypad {
+        Keypad::new(" 1 \n234\n 5 ")
     }
 
     #[rstest]
-    fn button_location_button_number_test_ok(keypad_layout: Vec<(ButtonLocation, ButtonNumber)>) {
-        let location = ButtonLocation::at(0, 0);
-
-        let button_number = location.button_number(HashMap::from_iter(keypad_layout));
+    fn keypad_button_number_test_ok(keypad: Keypad) {
+        let location = ButtonLocation::new(0, 0);
 
-        assert_eq!(ButtonNumber('0'), button_number);
+        assert_eq!(ButtonNumber('3'), keypad.button_number(location));
     }
 
     #[rstest]
     #[should_panic]
-    fn button_location_button_number_test_err(keypad_layout: Vec<(ButtonLocation, ButtonNumber)>) {
-        let location = ButtonLocation::at(5, -5);
+    fn keypad_button_number_test_err(keypad: Keypad) {
+        let location = ButtonLocation::new(5, -5);
 
-        location.button_number(HashMap::from_iter(keypad_layout));
+        keypad.button_number(location);
     }
 
     #[rstest]
-    #[case(ButtonLocation::at(0, 2), ButtonLocation::at(0, 1))]
-    #[case(ButtonLocation::at(0, -2), ButtonLocation::at(0, -1))]
-    #[case(ButtonLocation::at(2, 0), ButtonLocation::at(1, 0))]
-    #[case(ButtonLocation::at(-2, 0), ButtonLocation::at(-1, 0))]
-    fn button_location_trait_add_test(
-        #[case] expected: ButtonLocation,
-        #[case] summand: ButtonLocation,
-    ) {
-        let sum = summand + summand;
-
-        assert_eq!(expected, sum);
-    }
-
-    #[rstest]
-    #[case(ButtonLocation::at(0, 1), Instruction::Up)]
-    #[case(ButtonLocation::at(0, -1), Instruction::Down)]
-    #[case(ButtonLocation::at(1, 0), Instruction::Right)]
-    #[case(ButtonLocation::at(-1, 0), Instruction::Left)]
+    #[case(ButtonLocation::new(0, 1), Instruction::Up)]
+    #[case(ButtonLocation::new(0, -1), Instruction::Down)]
+    #[case(ButtonLocation::new(1, 0), Instruction::Right)]
+    #[case(ButtonLocation::new(-1, 0), Instruction::Left)]
     fn follow_instruction_test_is_in_bounds(
         #[case] expected: ButtonLocation,
         #[case] instruction: Instruction,
@@ -131,25 +118,21 @@ mod tests {
     }
 
     #[rstest]
-    #[case(ButtonLocation::at(0, 0))]
-    #[case(ButtonLocation::at(0, 1))]
-    #[case(ButtonLocation::at(0, -1))]
-    #[case(ButtonLocation::at(1, 0))]
-    #[case(ButtonLocation::at(-1, 0))]
-    #[case(ButtonLocation::at(1, 1))]
-    #[case(ButtonLocation::at(1, -1))]
-    #[case(ButtonLocation::at(-1, 1))]
-    #[case(ButtonLocation::at(-1, -1))]
-    fn keypad_button_is_in_3_times_3_grid_test_true(#[case] location: ButtonLocation) {
-        assert!(super::is_in_3_times_3_grid(location));
-    }
-
-    #[rstest]
-    #[case(ButtonLocation::at(0, 2))]
-    #[case(ButtonLocation::at(0, -2))]
-    #[case(ButtonLocation::at(2, 0))]
-    #[case(ButtonLocation::at(-2, 0))]
-    fn keypad_button_is_in_3_times_3_grid_test_false(#[case] location: ButtonLocation) {
-        assert!(!super::is_in_3_times_3_grid(location));
+    #[case(Some(ButtonNumber('1')), ButtonLocation::new(0, 1))]
+    #[case(Some(ButtonNumber('2')), ButtonLocation::new(-1, 0))]
+    #[case(Some(ButtonNumber('3')), ButtonLocation::new(0, 0))]
+    #[case(Some(ButtonNumber('4')), ButtonLocation::new(1, 0))]
+    #[case(Some(ButtonNumber('5')), ButtonLocation::new(0, -1))]
+    #[case(None, ButtonLocation::new(1, 1))]
+    #[case(None, ButtonLocation::new(-1, -1))]
+    #[case(None, ButtonLocation::new(0, 2))]
+    #[case(None, ButtonLocation::new(-2, 0))]
+    fn keypad_get_test(
+        keypad: Keypad,
+        #[case] expected: Option<ButtonNumber>,
+        #[case] location: ButtonLocation,
+    ) {
+        assert_eq!(expected, keypad.get(location));
+        assert_eq!(expected.is_some(), keypad.contains(location));
     }
 }
@@ -1,24 +1,11 @@
-use std::collections::HashMap;
-
-use crate::instruction::Instruction;
-
-use super::{Button, ButtonLocation, ButtonNumber};
-
-static KEYPAD_LAYOUT: [(ButtonLocation, ButtonNumber); 13] = [
-    (ButtonLocation::at(0, 2), ButtonNumber('1')),
-    (ButtonLocation::at(-1, 1), ButtonNumber('2')),
-    (ButtonLocation::at(0, 1), ButtonNumber('3')),
-    (ButtonLocation::at(1, 1), ButtonNumber('4')),
-    (ButtonLocation::at(-2, 0), ButtonNumber('5')),
-    (ButtonLocation::at(-1, 0), ButtonNumber('6')),
-    (ButtonLocation::at(0, 0), ButtonNumber('7')),
-    (ButtonLocation::at(1, 0), ButtonNumber('8')),
-    (ButtonLocation::at(2, 0), ButtonNumber('9')),
-    (ButtonLocation::at(-1, -1), ButtonNumber('A')),
-    (ButtonLocation::at(0, -1), ButtonNumber('B')),
-    (ButtonLocation::at(1, -1), ButtonNumber('C')),
-    (ButtonLocation::at(0, -2), ButtonNumber('D')),
-];
+use std::sync::LazyLock;
+
+use crate::y2016::d02::instruction::Instruction;
+
+use super::{Button, ButtonLocation, ButtonNumber, Keypad};
+
+static KEYPAD: LazyLock<Keypad> =
+    LazyLock::new(|| Keypad::new("  1  \n 234 \n56789\n ABC \n  D  "));
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct KeypadButton {
@@ -28,32 +15,25 @@ pub struct KeypadButton {
 impl KeypadButton {
     pub fn at_start() -> Self {
         Self {
-            location: ButtonLocation::at(-2, 0),
+            location: ButtonLocation::new(-2, 0),
         }
     }
 
     #[cfg(test)]
     pub fn at_location(x: i8, y: i8) -> Self {
         Self {
-            location: ButtonLocation::at(x, y),
+            location: ButtonLocation::new(x, y),
         }
     }
 
     fn is_in_bounds(location: ButtonLocation) -> bool {
-        Self::is_corner(location) || super::is_in_3_times_3_grid(location)
-    }
-
-    fn is_corner(location: ButtonLocation) -> bool {
-        let corner_range = [-2, 2];
-        let is_x_dimension_corner = corner_range.contains(&location.x) && location.y == 0;
-        let is_y_dimension_corner = corner_range.contains(&location.y) && location.x == 0;
-        is_x_dimension_corner || is_y_dimension_corner
+        KEYPAD.contains(location)
     }
 }
 
 impl Button for KeypadButton {
     fn button_number(&self) -> ButtonNumber {
-        self.location.button_number(HashMap::from(KEYPAD_LAYOUT))
+        KEYPAD.button_number(self.location)
     }
 
     fn follow_instruction(&mut self, instruction: Instruction) {
@@ -89,49 +69,30 @@ mod tests {
     }
 
     #[rstest]
-    #[case(ButtonLocation::at(0, 2))]
-    #[case(ButtonLocation::at(0, -2))]
-    #[case(ButtonLocation::at(2, 0))]
-    #[case(ButtonLocation::at(-2, 0))]
-    #[case(ButtonLocation::at(0, 0))]
-    #[case(ButtonLocation::at(0, 1))]
-    #[case(ButtonLocation::at(0, -1))]
-    #[case(ButtonLocation::at(1, 0))]
-    #[case(ButtonLocation::at(-1, 0))]
-    #[case(ButtonLocation::at(1, 1))]
-    #[case(ButtonLocation::at(-1, -1))]
+    #[case(ButtonLocation::new(0, 2))]
+    #[case(ButtonLocation::new(0, -2))]
+    #[case(ButtonLocation::new(2, 0))]
+    #[case(ButtonLocation::new(-2, 0))]
+    #[case(ButtonLocation::new(0, 0))]
+    #[case(ButtonLocation::new(0, 1))]
+    #[case(ButtonLocation::new(0, -1))]
+    #[case(ButtonLocation::new(1, 0))]
+    #[case(ButtonLocation::new(-1, 0))]
+    #[case(ButtonLocation::new(1, 1))]
+    #[case(ButtonLocation::new(-1, -1))]
     fn keypad_button_is_in_bounds_test_true(#[case] location: ButtonLocation) {
         assert!(KeypadButton::is_in_bounds(location));
     }
 
     #[rstest]
-    #[case(ButtonLocation::at(1, 2))]
-    #[case(ButtonLocation::at(-1, -2))]
-    #[case(ButtonLocation::at(2, 1))]
-    #[case(ButtonLocation::at(-2, -1))]
+    #[case(ButtonLocation::new(1, 2))]
+    #[case(ButtonLocation::new(-1, -2))]
+    #[case(ButtonLocation::new(2, 1))]
+    #[case(ButtonLocation::new(-2, -1))]
     fn keypad_button_is_in_bounds_test_false(#[case] location: ButtonLocation) {
         assert!(!KeypadButton::is_in_bounds(location));
     }
 
-    #[rstest]
-    #[case(ButtonLocation::at(0, 2))]
-    #[case(ButtonLocation::at(0, -2))]
-    #[case(ButtonLocation::at(2, 0))]
-    #[case(ButtonLocation::at(-2, 0))]
-    fn keypad_button_is_corner_test_true(#[case] location: ButtonLocation) {
-        assert!(KeypadButton::is_corner(location));
-    }
-
-    #[rstest]
-    #[case(ButtonLocation::at(0, 0))]
-    #[case(ButtonLocation::at(1, 2))]
-    #[case(ButtonLocation::at(-1, -2))]
-    #[case(ButtonLocation::at(2, 1))]
-    #[case(ButtonLocation::at(-2, -1))]
-    fn keypad_button_is_corner_test_false(#[case] location: ButtonLocation) {
-        assert!(!KeypadButton::is_corner(location));
-    }
-
     #[rstest]
     #[case(KeypadButton::at_location(0, 1), Instruction::Up)]
     #[case(KeypadButton::at_location(0, -1), Instruction::Down)]
@@ -1,19 +1,9 @@
-use std::collections::HashMap;
-
-use super::{Button, ButtonLocation, ButtonNumber};
-use crate::instruction::Instruction;
-
-static KEYPAD_LAYOUT: [(ButtonLocation, ButtonNumber); 9] = [
-    (ButtonLocation::at(-1, 1), ButtonNumber('1')),
-    (ButtonLocation::at(0, 1), ButtonNumber('2')),
-    (ButtonLocation::at(1, 1), ButtonNumber('3')),
-    (ButtonLocation::at(-1, 0), ButtonNumber('4')),
-    (ButtonLocation::at(0, 0), ButtonNumber('5')),
-    (ButtonLocation::at(1, 0), ButtonNumber('6')),
-    (ButtonLocation::at(-1, -1), ButtonNumber('7')),
-    (ButtonLocation::at(0, -1), ButtonNumber('8')),
-    (ButtonLocation::at(1, -1), ButtonNumber('9')),
-];
+use std::sync::LazyLock;
+
+use super::{Button, ButtonLocation, ButtonNumber, Keypad};
+use crate::y2016::d02::instruction::Instruction;
+
+static KEYPAD: LazyLock<Keypad> = LazyLock::new(|| Keypad::new("123\n456\n789"));
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct KeypadButton {
@@ -30,19 +20,20 @@ impl KeypadButton {
     #[cfg(test)]
     pub fn at_location(x: i8, y: i8) -> Self {
         Self {
-            location: ButtonLocation::at(x, y),
+            location: ButtonLocation::new(x, y),
         }
     }
 }
 
 impl Button for KeypadButton {
     fn button_number(&self) -> ButtonNumber {
-        self.location.button_number(HashMap::from(KEYPAD_LAYOUT))
+        KEYPAD.button_number(self.location)
     }
 
     fn follow_instruction(&mut self, instruction: Instruction) {
-        self.location =
-            super::follow_instruction(self.location, instruction, super::is_in_3_times_3_grid);
+        self.location = super::follow_instruction(self.location, instruction, |location| {
+            KEYPAD.contains(location)
+        });
     }
 }
 
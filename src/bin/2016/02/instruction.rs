@@ -2,7 +2,9 @@ use std::str::FromStr;
 
 use snafu::prelude::*;
 
-use crate::door_code::{Button, ButtonLocation, ButtonNumber};
+use util::geometry::{Direction, Vector};
+
+use crate::y2016::d02::door_code::{Button, ButtonNumber};
 
 #[derive(Debug, PartialEq, Snafu)]
 #[snafu(display("String could not be parsed into `CodeInstructions` because of char '{}' at index {}.", source.invalid_char, invalid_char_index))]
@@ -47,7 +49,7 @@ impl FromStr for CodeInstructions {
                 Err(error) => {
                     return Err(CodeInstructionsParseError {
                         source: error,
-                        parsed: String::from(&string[..index]),
+                        parsed: string.chars().take(index).collect(),
                         invalid_char_index: index,
                     })
                 }
@@ -66,13 +68,14 @@ pub enum Instruction {
 }
 
 impl Instruction {
-    pub fn button_position_offset(&self) -> ButtonLocation {
-        match self {
-            Self::Up => ButtonLocation::at(0, 1),
-            Self::Down => ButtonLocation::at(0, -1),
-            Self::Right => ButtonLocation::at(1, 0),
-            Self::Left => ButtonLocation::at(-1, 0),
-        }
+    pub fn button_position_offset(&self) -> Vector<i8> {
+        let direction = match self {
+            Self::Up => Direction::North,
+            Self::Down => Direction::South,
+            Self::Right => Direction::East,
+            Self::Left => Direction::West,
+        };
+        direction.vector()
     }
 }
 
@@ -98,7 +101,7 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
-    use crate::door_code::normal_keypad;
+    use crate::y2016::d02::door_code::normal_keypad;
 
     #[test]
     fn code_instructions_new_test() {
@@ -164,12 +167,12 @@ mod tests {
     }
 
     #[rstest]
-    #[case(ButtonLocation::at(0, 1), Instruction::Up)]
-    #[case(ButtonLocation::at(0, -1), Instruction::Down)]
-    #[case(ButtonLocation::at(1, 0), Instruction::Right)]
-    #[case(ButtonLocation::at(-1, 0), Instruction::Left)]
+    #[case(Vector::new(0, 1), Instruction::Up)]
+    #[case(Vector::new(0, -1), Instruction::Down)]
+    #[case(Vector::new(1, 0), Instruction::Right)]
+    #[case(Vector::new(-1, 0), Instruction::Left)]
     fn instruction_button_position_offset_test(
-        #[case] expected: ButtonLocation,
+        #[case] expected: Vector<i8>,
         #[case] instruction: Instruction,
     ) {
         assert_eq!(expected, instruction.button_position_offset());
@@ -1,43 +1,40 @@
 mod door_code;
 mod instruction;
 
-use util::std::*;
+use std::{convert::Infallible, fmt::Display};
 
 use door_code::{diffuse_keypad, normal_keypad, Button};
 use instruction::{CodeInstructions, CodeInstructionsParseError};
+use util::aoc::{Parameters, Solution};
 
-fn main() {
-    let input = read_file(InputFile::Actual, Year("2016"), Day("02"))
-        .expect("Input file could not be read.");
-    let input = parse_input(&input);
+pub struct BathroomSecurity;
 
-    println!("Part 1: {}", part_1(&input));
-    println!("Part 2: {}", part_2(&input));
-}
-
-fn parse_input(input: &str) -> Vec<CodeInstructions> {
+fn solve(input: &[CodeInstructions], mut button: impl Button) -> String {
     input
-        .lines()
-        .map(|line| {
-            line.parse()
-                .unwrap_or_else(|error: CodeInstructionsParseError| panic!("{}", error))
-        })
+        .iter()
+        .map(|code_instructions| code_instructions.solve_code_number(&mut button).0)
         .collect()
 }
 
-fn part_1(input: &[CodeInstructions]) -> String {
-    solve(input, normal_keypad::KeypadButton::at_start())
-}
+impl Solution for BathroomSecurity {
+    const YEAR: u16 = 2016;
+    const DAY: u16 = 2;
 
-fn part_2(input: &[CodeInstructions]) -> String {
-    solve(input, diffuse_keypad::KeypadButton::at_start())
-}
+    type Input = Vec<CodeInstructions>;
+    type ParseError = CodeInstructionsParseError;
+    type SolveError = Infallible;
 
-fn solve(input: &[CodeInstructions], mut button: impl Button) -> String {
-    input
-        .iter()
-        .map(|code_instructions| code_instructions.solve_code_number(&mut button).0)
-        .collect()
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        input.lines().map(str::parse).collect()
+    }
+
+    fn part_one(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(solve(input, normal_keypad::KeypadButton::at_start()))
+    }
+
+    fn part_two(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(solve(input, diffuse_keypad::KeypadButton::at_start()))
+    }
 }
 
 #[cfg(test)]
@@ -45,7 +42,7 @@ mod tests {
     use rstest::{fixture, rstest};
 
     use super::*;
-    use crate::instruction::Instruction;
+    use crate::y2016::d02::instruction::Instruction;
 
     #[fixture]
     fn input_lines() -> Vec<String> {
@@ -53,7 +50,7 @@ mod tests {
     }
 
     #[rstest]
-    fn parse_input_test_ok(input_lines: Vec<String>) {
+    fn parse_test_ok(input_lines: Vec<String>) {
         let input = input_lines.join("\n");
         let expected = vec![
             CodeInstructions::new(vec![
@@ -70,15 +67,14 @@ mod tests {
             ]),
         ];
 
-        assert_eq!(expected, parse_input(&input));
+        assert_eq!(Ok(expected), BathroomSecurity::parse(&input));
     }
 
     #[rstest]
-    #[should_panic]
-    fn parse_input_test_err(input_lines: Vec<String>) {
+    fn parse_test_err(input_lines: Vec<String>) {
         let invalid_input = input_lines.join("*");
 
-        parse_input(&invalid_input);
+        assert!(BathroomSecurity::parse(&invalid_input).is_err());
     }
 
     #[fixture]
@@ -110,12 +106,22 @@ mod tests {
     }
 
     #[rstest]
-    fn part_1_test(aoc_test_input: Vec<CodeInstructions>) {
-        assert_eq!(String::from("1985"), part_1(&aoc_test_input));
+    fn part_one_test(aoc_test_input: Vec<CodeInstructions>) {
+        assert_eq!(
+            String::from("1985"),
+            BathroomSecurity::part_one(&aoc_test_input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
     }
 
     #[rstest]
-    fn part_2_test(aoc_test_input: Vec<CodeInstructions>) {
-        assert_eq!(String::from("5DB3"), part_2(&aoc_test_input));
+    fn part_two_test(aoc_test_input: Vec<CodeInstructions>) {
+        assert_eq!(
+            String::from("5DB3"),
+            BathroomSecurity::part_two(&aoc_test_input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
     }
 }
@@ -0,0 +1,84 @@
+mod parser;
+mod triangle;
+
+use std::{convert::Infallible, fmt::Display};
+
+use parser::{parse_input_horizontal, parse_input_vertical, TrianglesParseError};
+use triangle::TriangleCollection;
+use util::aoc::{Parameters, Solution};
+
+pub struct SquaresWithThreeSides;
+
+pub struct Triangles {
+    horizontal: TriangleCollection,
+    vertical: TriangleCollection,
+}
+
+impl Solution for SquaresWithThreeSides {
+    const YEAR: u16 = 2016;
+    const DAY: u16 = 3;
+
+    type Input = Triangles;
+    type ParseError = TrianglesParseError;
+    type SolveError = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        Ok(Triangles {
+            horizontal: parse_input_horizontal(input)?,
+            vertical: parse_input_vertical(input)?,
+        })
+    }
+
+    fn part_one(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(input
+            .horizontal
+            .iter()
+            .filter(|triangle| triangle.is_valid())
+            .count())
+    }
+
+    fn part_two(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(input
+            .vertical
+            .iter()
+            .filter(|triangle| triangle.is_valid())
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn raw_input() -> String {
+        let input_lines = ["  676  739   39", "  890   40  865", "    2  735  297"];
+        input_lines.join("\n")
+    }
+
+    #[rstest]
+    fn part_one_test(raw_input: String) {
+        let input = SquaresWithThreeSides::parse(&raw_input).unwrap();
+
+        assert_eq!(
+            "1",
+            SquaresWithThreeSides::part_one(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[rstest]
+    fn part_two_test(raw_input: String) {
+        let input = SquaresWithThreeSides::parse(&raw_input).unwrap();
+
+        assert_eq!(
+            "1",
+            SquaresWithThreeSides::part_two(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+}
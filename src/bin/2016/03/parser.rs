@@ -1,26 +1,52 @@
 mod horizontal;
 mod vertical;
 
+use std::num::ParseIntError;
+
+use snafu::prelude::*;
+
 pub use horizontal::parse_input_horizontal;
 pub use vertical::parse_input_vertical;
 
-fn parse_sides(line: &str) -> [usize; 3] {
-    let sides: Vec<usize> = [&line[2..=4], &line[7..=9], &line[12..]]
-        .into_iter()
-        .map(parse_side)
-        .collect();
+#[derive(Debug, PartialEq, Snafu)]
+pub enum TrianglesParseError {
+    #[snafu(display("Could not parse side \"{string}\" in line {line_number}: {source}"))]
+    InvalidSide {
+        source: ParseIntError,
+        string: String,
+        line_number: usize,
+    },
+    #[snafu(display("Expected 3 sides in line {line_number} but found {count}."))]
+    WrongSideCount { count: usize, line_number: usize },
+    #[snafu(display("The number of lines ({line_count}) is not divisible by 3."))]
+    IncompleteColumns { line_count: usize },
+}
 
-    match sides.as_slice() {
-        &[a, b, c] => [a, b, c],
-        _ => panic!(
-            "There are less than 3 numbers in one line of the input. Content: \"{}\", found: {:?}.",
-            line, sides
-        ),
-    }
+fn parse_lines(input: &str) -> Result<Vec<[usize; 3]>, TrianglesParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_sides(line, index + 1))
+        .collect()
 }
 
-fn parse_side(side: &str) -> usize {
-    String::from(side.trim())
-        .parse()
-        .unwrap_or_else(|_| panic!("Could not parse string \"{}\".", side))
+fn parse_sides(line: &str, line_number: usize) -> Result<[usize; 3], TrianglesParseError> {
+    let sides: Vec<usize> = line
+        .split_whitespace()
+        .map(|side| {
+            side.parse().context(InvalidSideSnafu {
+                string: side,
+                line_number,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    match sides.as_slice() {
+        &[a, b, c] => Ok([a, b, c]),
+        _ => WrongSideCountSnafu {
+            count: sides.len(),
+            line_number,
+        }
+        .fail(),
+    }
 }
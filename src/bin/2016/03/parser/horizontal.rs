@@ -1,7 +1,8 @@
-use crate::triangle::TriangleCollection;
+use super::TrianglesParseError;
+use crate::y2016::d03::triangle::TriangleCollection;
 
-pub fn parse_input_horizontal(input: &str) -> TriangleCollection {
-    input.lines().map(super::parse_sides).collect()
+pub fn parse_input_horizontal(input: &str) -> Result<TriangleCollection, TrianglesParseError> {
+    Ok(super::parse_lines(input)?.into_iter().collect())
 }
 
 #[cfg(test)]
@@ -9,7 +10,7 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
-    use crate::triangle::Triangle;
+    use crate::y2016::d03::triangle::Triangle;
 
     #[rstest]
     fn parse_input_horizontal_test_ok() {
@@ -32,22 +33,29 @@ mod tests {
             },
         ]);
 
-        assert_eq!(expected, parse_input_horizontal(&actual));
+        assert_eq!(Ok(expected), parse_input_horizontal(&actual));
     }
 
     #[test]
-    #[should_panic]
     fn parse_input_horizontal_test_err_not_enough_sides_per_line() {
         let input = "  1    2";
+        let expected = TrianglesParseError::WrongSideCount {
+            count: 2,
+            line_number: 1,
+        };
 
-        parse_input_horizontal(input);
+        assert_eq!(Err(expected), parse_input_horizontal(input));
     }
 
     #[test]
-    #[should_panic]
     fn parse_input_horizontal_test_err_no_valid_number() {
         let input = "  1    A    3";
+        let expected = TrianglesParseError::InvalidSide {
+            source: "A".parse::<usize>().unwrap_err(),
+            string: "A".to_string(),
+            line_number: 1,
+        };
 
-        parse_input_horizontal(input);
+        assert_eq!(Err(expected), parse_input_horizontal(input));
     }
 }
@@ -1,22 +1,18 @@
-use itertools::Itertools;
+use super::TrianglesParseError;
+use crate::y2016::d03::triangle::TriangleCollection;
 
-use crate::triangle::TriangleCollection;
-
-pub fn parse_input_vertical(input: &str) -> TriangleCollection {
-    input
-        .lines()
-        .chunks(3)
-        .into_iter()
-        .flat_map(|lines| {
-            let sides_of_triangles: [_; 3] = lines
-                .map(super::parse_sides)
-                .collect::<Vec<_>>()
-                .try_into()
-                .unwrap_or_else(|_| panic!("Amount of lines is not divisible by 3."));
+pub fn parse_input_vertical(input: &str) -> Result<TriangleCollection, TrianglesParseError> {
+    let rows = super::parse_lines(input)?;
+    if rows.len() % 3 != 0 {
+        return Err(TrianglesParseError::IncompleteColumns {
+            line_count: rows.len(),
+        });
+    }
 
-            rows_to_columns(sides_of_triangles)
-        })
-        .collect()
+    Ok(rows
+        .chunks_exact(3)
+        .flat_map(|rows| rows_to_columns([rows[0], rows[1], rows[2]]))
+        .collect())
 }
 
 fn rows_to_columns(rows: [[usize; 3]; 3]) -> [[usize; 3]; 3] {
@@ -41,7 +37,7 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
-    use crate::triangle::Triangle;
+    use crate::y2016::d03::triangle::Triangle;
 
     #[rstest]
     fn parse_input_vertical_test_ok() {
@@ -64,30 +60,37 @@ mod tests {
             },
         ]);
 
-        assert_eq!(expected, parse_input_vertical(&actual));
+        assert_eq!(Ok(expected), parse_input_vertical(&actual));
     }
 
     #[test]
-    #[should_panic]
     fn parse_input_vertical_test_err_not_enough_sides_per_line() {
         let input = ["    1    2", "    4    5    6", "    7    8    9"].join("\n");
+        let expected = TrianglesParseError::WrongSideCount {
+            count: 2,
+            line_number: 1,
+        };
 
-        parse_input_vertical(&input);
+        assert_eq!(Err(expected), parse_input_vertical(&input));
     }
 
     #[test]
-    #[should_panic]
     fn parse_input_vertical_test_err_no_valid_number() {
         let input = ["    1    2    *", "    4    5    6", "    7    8    9"].join("\n");
+        let expected = TrianglesParseError::InvalidSide {
+            source: "*".parse::<usize>().unwrap_err(),
+            string: "*".to_string(),
+            line_number: 1,
+        };
 
-        parse_input_vertical(&input);
+        assert_eq!(Err(expected), parse_input_vertical(&input));
     }
 
     #[test]
-    #[should_panic]
     fn parse_input_vertical_test_err_number_of_lines_not_divisable_by_3() {
         let input = ["    1    2    3", "    4    5    6"].join("\n");
+        let expected = TrianglesParseError::IncompleteColumns { line_count: 2 };
 
-        parse_input_vertical(&input);
+        assert_eq!(Err(expected), parse_input_vertical(&input));
     }
 }
@@ -0,0 +1,143 @@
+use std::fmt::Display;
+
+use snafu::prelude::*;
+use util::{
+    aoc::{Parameters, Solution},
+    assembunny::{
+        Assembunny, AssembunnyParseError, HaltReason, MissingRegisterError, RegisterId,
+        RuntimeEnvironment, Word,
+    },
+};
+
+pub struct LeonardosMonorail;
+
+#[derive(Debug, PartialEq, Snafu)]
+pub enum ExecutionError {
+    #[snafu(display("{source}"), context(false))]
+    MissingRegister { source: MissingRegisterError },
+    #[snafu(display("The program did not halt ({reason:?} after {steps} steps)."))]
+    NotHalted { reason: HaltReason, steps: usize },
+}
+
+fn register_a_after_halt(
+    runtime_environment: Result<RuntimeEnvironment, MissingRegisterError>,
+) -> Result<Word, ExecutionError> {
+    let mut runtime_environment = runtime_environment?;
+    let outcome = runtime_environment.run_compiled();
+    ensure!(
+        outcome.reason == HaltReason::Halted,
+        NotHaltedSnafu {
+            reason: outcome.reason,
+            steps: outcome.steps,
+        }
+    );
+    Ok(runtime_environment.register_value(RegisterId::A))
+}
+
+impl Solution for LeonardosMonorail {
+    const YEAR: u16 = 2016;
+    const DAY: u16 = 12;
+
+    type Input = Assembunny;
+    type ParseError = AssembunnyParseError;
+    type SolveError = ExecutionError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        input.parse()
+    }
+
+    fn part_one(
+        assembunny: &Self::Input,
+        _: &Parameters,
+    ) -> Result<impl Display, Self::SolveError> {
+        register_a_after_halt(RuntimeEnvironment::load_assembunny(assembunny.optimized()))
+    }
+
+    fn part_two(
+        assembunny: &Self::Input,
+        _: &Parameters,
+    ) -> Result<impl Display, Self::SolveError> {
+        register_a_after_halt(
+            RuntimeEnvironment::load_assembunny_with_initialized_registers(
+                assembunny.optimized(),
+                [0, 0, 1, 0].into(),
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use util::assembunny::{Argument, Instruction};
+
+    use super::*;
+
+    #[fixture]
+    fn aoc_assembunny() -> Assembunny {
+        Assembunny(vec![
+            Instruction::Cpy {
+                from: Argument::Literal(41),
+                into: Argument::Reference(RegisterId::A),
+            },
+            Instruction::Inc(Argument::Reference(RegisterId::A)),
+            Instruction::Inc(Argument::Reference(RegisterId::A)),
+            Instruction::Dec(Argument::Reference(RegisterId::A)),
+            Instruction::Jnz {
+                condition: Argument::Reference(RegisterId::A),
+                jump_offset: Argument::Literal(2),
+            },
+            Instruction::Dec(Argument::Reference(RegisterId::A)),
+        ])
+    }
+
+    #[rstest]
+    fn part_one_test(aoc_assembunny: Assembunny) {
+        assert_eq!(
+            "42",
+            LeonardosMonorail::part_one(&aoc_assembunny, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[rstest]
+    fn part_two_test(aoc_assembunny: Assembunny) {
+        assert_eq!(
+            "42",
+            LeonardosMonorail::part_two(&aoc_assembunny, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part_one_test_loop() {
+        let assembunny = "cpy 1 a\njnz a 0".parse().unwrap();
+
+        let expected = ExecutionError::NotHalted {
+            reason: HaltReason::LoopDetected,
+            steps: 3,
+        };
+
+        assert_eq!(
+            Some(expected),
+            LeonardosMonorail::part_one(&assembunny, &Parameters::new()).err()
+        );
+    }
+
+    #[test]
+    fn part_one_test_missing_register() {
+        let assembunny = "inc a\ninc e".parse().unwrap();
+
+        let error = LeonardosMonorail::part_one(&assembunny, &Parameters::new())
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            "Instruction 1 uses the register `e`, but there are only 4 registers.",
+            error.to_string()
+        );
+    }
+}
@@ -0,0 +1,17 @@
+#[path = "01/mod.rs"]
+mod d01;
+#[path = "02/mod.rs"]
+mod d02;
+#[path = "03/mod.rs"]
+mod d03;
+#[path = "12/mod.rs"]
+mod d12;
+
+use util::aoc::Registry;
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<d01::NoTimeForATaxicab>();
+    registry.register::<d02::BathroomSecurity>();
+    registry.register::<d03::SquaresWithThreeSides>();
+    registry.register::<d12::LeonardosMonorail>();
+}
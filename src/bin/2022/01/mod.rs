@@ -0,0 +1,88 @@
+use std::{convert::Infallible, fmt::Display, num::ParseIntError};
+
+use snafu::prelude::*;
+use util::aoc::{Parameters, Solution};
+
+pub struct CalorieCounting;
+
+#[derive(Debug, PartialEq, Snafu)]
+#[snafu(display("Could not parse the calories in line {line_number}: {source}"))]
+pub struct CaloriesParseError {
+    source: ParseIntError,
+    line_number: usize,
+}
+
+impl Solution for CalorieCounting {
+    const YEAR: u16 = 2022;
+    const DAY: u16 = 1;
+
+    type Input = Vec<i32>;
+    type ParseError = CaloriesParseError;
+    type SolveError = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        let mut elves = vec![0];
+        for (index, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                elves.push(0);
+                continue;
+            }
+            let calories = line.parse::<i32>().context(CaloriesParseSnafu {
+                line_number: index + 1,
+            })?;
+            *elves.last_mut().unwrap() += calories;
+        }
+        Ok(elves)
+    }
+
+    fn part_one(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(*input.iter().max().unwrap())
+    }
+
+    fn part_two(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        let mut sums = input.clone();
+        sums.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(sums[..3].iter().sum::<i32>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../../res/2022/01/example.txt");
+
+    #[test]
+    fn parse_test_err() {
+        let expected = CaloriesParseError {
+            source: "1x".parse::<i32>().unwrap_err(),
+            line_number: 3,
+        };
+
+        assert_eq!(Err(expected), CalorieCounting::parse("1000\n\n1x"));
+    }
+
+    #[test]
+    fn part_one_test() {
+        let input = CalorieCounting::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "24000",
+            CalorieCounting::part_one(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = CalorieCounting::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "45000",
+            CalorieCounting::part_two(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+}
@@ -1,6 +1,13 @@
 use std::{cmp::Ordering, str::FromStr};
 
 use num_derive::FromPrimitive;
+use snafu::prelude::*;
+
+#[derive(Debug, PartialEq, Snafu)]
+#[snafu(display("Command '{command}' could not be parsed."))]
+pub struct CommandParseError {
+    command: String,
+}
 
 #[derive(Clone, Copy, PartialEq, FromPrimitive)]
 pub enum Command {
@@ -10,14 +17,14 @@ pub enum Command {
 }
 
 impl FromStr for Command {
-    type Err = String;
+    type Err = CommandParseError;
 
     fn from_str(command: &str) -> Result<Self, <Self as FromStr>::Err> {
         match command {
             "A" | "X" => Ok(Self::Rock),
             "B" | "Y" => Ok(Self::Paper),
             "C" | "Z" => Ok(Self::Scissors),
-            _ => Err(format!("Command {} could not be parsed.", command)),
+            _ => CommandParseSnafu { command }.fail(),
         }
     }
 }
@@ -1,7 +1,7 @@
 use std::cmp::Ordering;
 
-use crate::command::Command;
-use crate::outcome::Outcome;
+use crate::y2022::d02::command::Command;
+use crate::y2022::d02::outcome::Outcome;
 
 pub struct CommandRound {
     pub opponent: Command,
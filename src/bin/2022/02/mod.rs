@@ -0,0 +1,144 @@
+use std::{convert::Infallible, fmt::Display, str::FromStr};
+
+use command::{Command, CommandParseError};
+use command_round::CommandRound;
+use outcome::{Outcome, OutcomeParseError};
+use outcome_round::OutcomeRound;
+use snafu::prelude::*;
+use util::aoc::{Parameters, Solution};
+
+mod command;
+mod command_round;
+mod outcome;
+mod outcome_round;
+
+pub struct RockPaperScissors;
+
+#[derive(Debug, PartialEq, Snafu)]
+#[snafu(display("Could not parse line {line_number} of the strategy guide: {source}"))]
+pub struct StrategyGuideParseError {
+    source: StrategyGuideLineParseError,
+    line_number: usize,
+}
+
+#[derive(Debug, PartialEq, Snafu)]
+pub enum StrategyGuideLineParseError {
+    #[snafu(display("Command of opponent was missing."))]
+    MissingOpponent,
+    #[snafu(display("Response to opponent was missing."))]
+    MissingResponse,
+    #[snafu(display("{source}"), context(false))]
+    InvalidCommand { source: CommandParseError },
+    #[snafu(display("{source}"), context(false))]
+    InvalidOutcome { source: OutcomeParseError },
+}
+
+pub struct StrategyGuideLine {
+    opponent: Command,
+    player: Command,
+    outcome: Outcome,
+}
+
+impl FromStr for StrategyGuideLine {
+    type Err = StrategyGuideLineParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut iter = line.split(' ').filter(|part| !part.is_empty());
+
+        let opponent = iter.next().context(MissingOpponentSnafu)?;
+        let response = iter.next().context(MissingResponseSnafu)?;
+
+        Ok(Self {
+            opponent: Command::from_str(opponent)?,
+            player: Command::from_str(response)?,
+            outcome: Outcome::from_str(response)?,
+        })
+    }
+}
+
+impl Solution for RockPaperScissors {
+    const YEAR: u16 = 2022;
+    const DAY: u16 = 2;
+
+    type Input = Vec<StrategyGuideLine>;
+    type ParseError = StrategyGuideParseError;
+    type SolveError = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                StrategyGuideLine::from_str(line).context(StrategyGuideParseSnafu {
+                    line_number: index + 1,
+                })
+            })
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(input
+            .iter()
+            .map(|line| {
+                let rounds = CommandRound::new(&line.opponent, &line.player);
+                rounds.round_outcome() as u32 + rounds.player as u32
+            })
+            .sum::<u32>())
+    }
+
+    fn part_two(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(input
+            .iter()
+            .map(|line| {
+                let rounds = OutcomeRound::new(&line.opponent, &line.outcome);
+                rounds.player_command() as u32 + rounds.outcome as u32
+            })
+            .sum::<u32>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../../res/2022/02/example.txt");
+
+    #[rstest]
+    #[case("A Y\n\n", StrategyGuideLineParseError::MissingOpponent)]
+    #[case("A Y\nB", StrategyGuideLineParseError::MissingResponse)]
+    #[case("A Y\nD X", Command::from_str("D").err().unwrap().into())]
+    fn parse_test_err(#[case] input: &str, #[case] source: StrategyGuideLineParseError) {
+        let expected = StrategyGuideParseError {
+            source,
+            line_number: 2,
+        };
+
+        assert_eq!(expected, RockPaperScissors::parse(input).err().unwrap());
+    }
+
+    #[test]
+    fn part_one_test() {
+        let input = RockPaperScissors::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "15",
+            RockPaperScissors::part_one(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = RockPaperScissors::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "12",
+            RockPaperScissors::part_two(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+}
@@ -1,5 +1,13 @@
 use std::str::FromStr;
 
+use snafu::prelude::*;
+
+#[derive(Debug, PartialEq, Snafu)]
+#[snafu(display("Outcome '{outcome}' could not be parsed."))]
+pub struct OutcomeParseError {
+    outcome: String,
+}
+
 #[derive(Clone, Copy)]
 pub enum Outcome {
     Win = 6,
@@ -8,14 +16,14 @@ pub enum Outcome {
 }
 
 impl FromStr for Outcome {
-    type Err = String;
+    type Err = OutcomeParseError;
 
     fn from_str(outcome: &str) -> Result<Self, <Self as FromStr>::Err> {
         match outcome {
             "X" => Ok(Self::Lose),
             "Y" => Ok(Self::Draw),
             "Z" => Ok(Self::Win),
-            _ => Err(format!("Outcome {} could not be parsed.", outcome)),
+            _ => OutcomeParseSnafu { outcome }.fail(),
         }
     }
 }
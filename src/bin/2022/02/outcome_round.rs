@@ -1,7 +1,7 @@
 use std::ops::RangeInclusive;
 
-use crate::command::Command;
-use crate::outcome::Outcome;
+use crate::y2022::d02::command::Command;
+use crate::y2022::d02::outcome::Outcome;
 
 use num_traits::FromPrimitive;
 
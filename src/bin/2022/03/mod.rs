@@ -0,0 +1,208 @@
+use std::{collections::HashSet, convert::Infallible, fmt::Display};
+
+use snafu::prelude::*;
+use util::aoc::{Parameters, Solution};
+
+pub struct RucksackReorganization;
+
+#[derive(Debug, PartialEq, Snafu)]
+#[snafu(display("Could not parse the rucksack in line {line_number}: {source}"))]
+pub struct RucksacksParseError {
+    source: RucksackParseError,
+    line_number: usize,
+}
+
+#[derive(Debug, PartialEq, Snafu)]
+pub enum RucksackParseError {
+    #[snafu(display("Char '{invalid_char}' at index {index} is not an item."))]
+    InvalidItem { invalid_char: char, index: usize },
+    #[snafu(display("The {item_count} items cannot be split evenly into two compartments."))]
+    UnevenCompartments { item_count: usize },
+}
+
+/// An item type, identified by its priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Item(u32);
+
+impl Item {
+    fn parse(index: usize, char: char) -> Result<Self, RucksackParseError> {
+        match char {
+            'a'..='z' => Ok(Self(char as u32 - 'a' as u32 + 1)),
+            'A'..='Z' => Ok(Self(char as u32 - 'A' as u32 + 27)),
+            _ => InvalidItemSnafu {
+                invalid_char: char,
+                index,
+            }
+            .fail(),
+        }
+    }
+
+    fn priority(&self) -> u32 {
+        self.0
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Rucksack {
+    first: Vec<Item>,
+    second: Vec<Item>,
+}
+
+impl Rucksack {
+    fn parse(line: &str) -> Result<Self, RucksackParseError> {
+        let mut items = line
+            .chars()
+            .enumerate()
+            .map(|(index, char)| Item::parse(index, char))
+            .collect::<Result<Vec<_>, _>>()?;
+        ensure!(
+            items.len() % 2 == 0,
+            UnevenCompartmentsSnafu {
+                item_count: items.len()
+            }
+        );
+
+        let second = items.split_off(items.len() / 2);
+        Ok(Self {
+            first: items,
+            second,
+        })
+    }
+
+    fn items(&self) -> impl Iterator<Item = &Item> {
+        self.first.iter().chain(&self.second)
+    }
+}
+
+fn unique_items<'a>(items: impl IntoIterator<Item = &'a Item>) -> HashSet<Item> {
+    items.into_iter().copied().collect()
+}
+
+impl Solution for RucksackReorganization {
+    const YEAR: u16 = 2022;
+    const DAY: u16 = 3;
+
+    type Input = Vec<Rucksack>;
+    type ParseError = RucksacksParseError;
+    type SolveError = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                Rucksack::parse(line).context(RucksacksParseSnafu {
+                    line_number: index + 1,
+                })
+            })
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        let unique_compartments: Vec<_> = input
+            .iter()
+            .map(|rucksack| {
+                (
+                    unique_items(&rucksack.first),
+                    unique_items(&rucksack.second),
+                )
+            })
+            .collect();
+
+        let duplicates: Vec<_> = unique_compartments
+            .iter()
+            .map(|(first, second)| *first.intersection(second).next().unwrap())
+            .collect();
+
+        let priorities: Vec<_> = duplicates.iter().map(Item::priority).collect();
+
+        Ok(priorities.iter().sum::<u32>())
+    }
+
+    fn part_two(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        let groups: Vec<_> = input.chunks(3).collect();
+
+        let unique_groups: Vec<_> = groups
+            .iter()
+            .map(|chunk| {
+                if let [first, second, third] = chunk {
+                    (
+                        unique_items(first.items()),
+                        unique_items(second.items()),
+                        unique_items(third.items()),
+                    )
+                } else {
+                    panic!("Chunk could not be split into 3 elements.");
+                }
+            })
+            .collect();
+
+        let duplicates: Vec<_> = unique_groups
+            .iter()
+            .map(|(first, second, third)| {
+                let duplicates: HashSet<_> = first.intersection(second).cloned().collect();
+                let duplicate = duplicates.intersection(third).next().unwrap();
+                *duplicate
+            })
+            .collect();
+
+        let priorities: Vec<_> = duplicates.iter().map(Item::priority).collect();
+
+        Ok(priorities.iter().sum::<u32>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../../res/2022/03/example.txt");
+
+    #[test]
+    fn parse_test() {
+        let expected = vec![Rucksack {
+            first: vec![Item(1), Item(27)],
+            second: vec![Item(26), Item(52)],
+        }];
+
+        assert_eq!(Ok(expected), RucksackReorganization::parse("aAzZ"));
+    }
+
+    #[rstest]
+    #[case("abAB\nab1B", RucksackParseError::InvalidItem { invalid_char: '1', index: 2 })]
+    #[case("abAB\nabA", RucksackParseError::UnevenCompartments { item_count: 3 })]
+    fn parse_test_err(#[case] input: &str, #[case] source: RucksackParseError) {
+        let expected = RucksacksParseError {
+            source,
+            line_number: 2,
+        };
+
+        assert_eq!(Err(expected), RucksackReorganization::parse(input));
+    }
+
+    #[test]
+    fn part_one_test() {
+        let input = RucksackReorganization::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "157",
+            RucksackReorganization::part_one(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = RucksackReorganization::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "70",
+            RucksackReorganization::part_two(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use std::{convert::Infallible, fmt::Display, num::ParseIntError, ops::RangeInclusive};
+
+use snafu::prelude::*;
+use util::aoc::{Parameters, Solution};
+
+pub struct CampCleanup;
+
+type SectionPair = (RangeInclusive<u8>, RangeInclusive<u8>);
+
+#[derive(Debug, PartialEq, Snafu)]
+#[snafu(display("Could not parse the section pair in line {line_number}: {source}"))]
+pub struct SectionPairsParseError {
+    source: SectionPairParseError,
+    line_number: usize,
+}
+
+#[derive(Debug, PartialEq, Snafu)]
+pub enum SectionPairParseError {
+    #[snafu(display("Expected two parts separated by '{separator}' in \"{string}\"."))]
+    MissingSeparator { separator: char, string: String },
+    #[snafu(display("Section \"{string}\" could not be parsed to a number: {source}"))]
+    InvalidSection {
+        source: ParseIntError,
+        string: String,
+    },
+}
+
+fn contains_all(outer: &RangeInclusive<u8>, inner: &RangeInclusive<u8>) -> bool {
+    outer.start() <= inner.start() && outer.end() >= inner.end()
+}
+
+fn contains_any(outer: &RangeInclusive<u8>, inner: &mut RangeInclusive<u8>) -> bool {
+    inner.any(|part| outer.contains(&part))
+}
+
+fn parse_section_pairs(input: &str) -> Result<Vec<SectionPair>, SectionPairsParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (first, second) = split_once(line, ',')?;
+            Ok((parse_range(first)?, parse_range(second)?))
+        })
+        .enumerate()
+        .map(|(index, result)| {
+            result.context(SectionPairsParseSnafu {
+                line_number: index + 1,
+            })
+        })
+        .collect()
+}
+
+fn parse_range(string: &str) -> Result<RangeInclusive<u8>, SectionPairParseError> {
+    let (start, end) = split_once(string, '-')?;
+    Ok(parse_section(start)?..=parse_section(end)?)
+}
+
+fn parse_section(string: &str) -> Result<u8, SectionPairParseError> {
+    string.parse().context(InvalidSectionSnafu { string })
+}
+
+fn split_once(string: &str, separator: char) -> Result<(&str, &str), SectionPairParseError> {
+    string
+        .split_once(separator)
+        .context(MissingSeparatorSnafu { separator, string })
+}
+
+impl Solution for CampCleanup {
+    const YEAR: u16 = 2022;
+    const DAY: u16 = 4;
+
+    type Input = Vec<SectionPair>;
+    type ParseError = SectionPairsParseError;
+    type SolveError = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        parse_section_pairs(input)
+    }
+
+    fn part_one(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(input
+            .iter()
+            .filter(|(first, second)| contains_all(first, second) || contains_all(second, first))
+            .count())
+    }
+
+    fn part_two(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(input
+            .iter()
+            .filter(|range| {
+                let (ref first, ref mut second) = (*range).clone();
+                contains_any(first, second)
+            })
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../../res/2022/04/example.txt");
+
+    #[rstest]
+    #[case("2-4,6-8\n2-3", SectionPairParseError::MissingSeparator {
+        separator: ',',
+        string: "2-3".to_string(),
+    })]
+    #[case("2-4,6-8\n2-3,4", SectionPairParseError::MissingSeparator {
+        separator: '-',
+        string: "4".to_string(),
+    })]
+    #[case("2-4,6-8\n2-3,4-x", SectionPairParseError::InvalidSection {
+        source: "x".parse::<u8>().unwrap_err(),
+        string: "x".to_string(),
+    })]
+    fn parse_test_err(#[case] input: &str, #[case] source: SectionPairParseError) {
+        let expected = SectionPairsParseError {
+            source,
+            line_number: 2,
+        };
+
+        assert_eq!(expected, CampCleanup::parse(input).unwrap_err());
+    }
+
+    #[test]
+    fn part_one_test() {
+        let input = CampCleanup::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "2",
+            CampCleanup::part_one(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = CampCleanup::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "4",
+            CampCleanup::part_two(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use std::{convert::Infallible, fmt::Display};
+
+use snafu::prelude::*;
+use util::aoc::{Parameters, Solution};
+
+pub struct SupplyStacks;
+
+#[derive(Debug, PartialEq, Snafu)]
+pub enum ProcedureParseError {
+    #[snafu(display("The drawing and the instructions must be separated by an empty line."))]
+    MissingInstructions,
+    #[snafu(display("The last line of the drawing does not number the stacks."))]
+    MissingStackNumbers,
+    #[snafu(display("Instruction {instruction_number} (\"{line}\") could not be parsed."))]
+    InvalidInstruction {
+        instruction_number: usize,
+        line: String,
+    },
+    #[snafu(display(
+        "Instruction {instruction_number} refers to stack {stack}, but there are only {stack_amount} stacks."
+    ))]
+    UnknownStack {
+        instruction_number: usize,
+        stack: u8,
+        stack_amount: usize,
+    },
+}
+
+type CrateStack = Vec<char>;
+
+pub struct Procedure {
+    crate_stacks: Vec<CrateStack>,
+    instructions: Vec<Instruction>,
+}
+
+struct Instruction {
+    amount: u8,
+    from: u8,
+    to: u8,
+}
+
+fn crate_stack_amount(last_line: &str) -> Option<usize> {
+    last_line
+        .chars()
+        .rfind(|char| char.is_ascii_digit())
+        .and_then(|char| char.to_digit(10))
+        .map(|amount| amount as usize)
+}
+
+fn parse_crate_stacks(crate_lines: &[&str], amount: usize) -> Vec<CrateStack> {
+    let mut crate_stacks = vec![CrateStack::new(); amount];
+    let crate_char_indices = (0..amount).map(|it| 4 * it + 1).collect::<Vec<_>>();
+
+    for line in crate_lines.iter().rev() {
+        let crate_chars = line
+            .chars()
+            .enumerate()
+            .filter(|(index, _)| crate_char_indices.contains(index))
+            .map(|(_, val)| val);
+
+        for (stack, crate_char) in crate_chars.enumerate() {
+            if crate_char.is_whitespace() {
+                continue;
+            }
+            crate_stacks[stack].push(crate_char);
+        }
+    }
+
+    crate_stacks
+}
+
+fn parse_instructions(
+    instruction_lines: Vec<&str>,
+    stack_amount: usize,
+) -> Result<Vec<Instruction>, ProcedureParseError> {
+    instruction_lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let instruction_number = index + 1;
+            let splitted = line
+                .split_whitespace()
+                .filter_map(|it| it.parse::<u8>().ok())
+                .collect::<Vec<_>>();
+            let [amount, from, to] = splitted[..] else {
+                return InvalidInstructionSnafu {
+                    instruction_number,
+                    line: *line,
+                }
+                .fail();
+            };
+            for stack in [from, to] {
+                ensure!(
+                    (1..=stack_amount).contains(&(stack as usize)),
+                    UnknownStackSnafu {
+                        instruction_number,
+                        stack,
+                        stack_amount,
+                    }
+                );
+            }
+            Ok(Instruction { amount, from, to })
+        })
+        .collect()
+}
+
+fn peek_crates(crate_stacks: &[CrateStack]) -> String {
+    crate_stacks
+        .iter()
+        .map(|it| it.last().unwrap())
+        .collect::<String>()
+}
+
+fn solve<F>(input: &Procedure, f: F) -> String
+where
+    F: Fn(&mut Vec<CrateStack>, &Instruction),
+{
+    let mut crate_stacks = input.crate_stacks.clone();
+
+    input
+        .instructions
+        .iter()
+        .for_each(|it| f(&mut crate_stacks, it));
+
+    peek_crates(&crate_stacks)
+}
+
+impl Solution for SupplyStacks {
+    const YEAR: u16 = 2022;
+    const DAY: u16 = 5;
+
+    type Input = Procedure;
+    type ParseError = ProcedureParseError;
+    type SolveError = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        let (drawing, instructions) = input.split_once("\n\n").context(MissingInstructionsSnafu)?;
+        let mut crate_lines = drawing.lines().collect::<Vec<_>>();
+        let instruction_lines = instructions.lines().collect::<Vec<_>>();
+
+        let crate_stack_amount = crate_lines
+            .pop()
+            .and_then(crate_stack_amount)
+            .context(MissingStackNumbersSnafu)?;
+
+        Ok(Procedure {
+            crate_stacks: parse_crate_stacks(&crate_lines, crate_stack_amount),
+            instructions: parse_instructions(instruction_lines, crate_stack_amount)?,
+        })
+    }
+
+    fn part_one(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(solve(input, |crate_stacks, instruction| {
+            let Instruction { amount, from, to } = *instruction;
+            for _ in 0..amount {
+                let popped = crate_stacks[from as usize - 1].pop().unwrap();
+                crate_stacks[to as usize - 1].push(popped);
+            }
+        }))
+    }
+
+    fn part_two(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(solve(input, |crate_stacks, instruction| {
+            let Instruction { amount, from, to } = *instruction;
+
+            let from_crate_stack = &crate_stacks[from as usize - 1];
+            let truncated_length = from_crate_stack.len() - amount as usize;
+            let removed = from_crate_stack[truncated_length..].to_vec();
+
+            crate_stacks[to as usize - 1].extend(removed);
+            crate_stacks[from as usize - 1].truncate(truncated_length);
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../../res/2022/05/example.txt");
+
+    #[rstest]
+    #[case("[A]\n 1 ", ProcedureParseError::MissingInstructions)]
+    #[case("[A]\n\nmove 1 from 1 to 1", ProcedureParseError::MissingStackNumbers)]
+    #[case("[A]\n 1 \n\nmove 1 from 1 to 1\nmove 1 from 1", ProcedureParseError::InvalidInstruction {
+        instruction_number: 2,
+        line: "move 1 from 1".to_string(),
+    })]
+    #[case("[A]\n 1 \n\nmove 1 from 1 to 2", ProcedureParseError::UnknownStack {
+        instruction_number: 1,
+        stack: 2,
+        stack_amount: 1,
+    })]
+    fn parse_test_err(#[case] input: &str, #[case] expected: ProcedureParseError) {
+        assert_eq!(expected, SupplyStacks::parse(input).err().unwrap());
+    }
+
+    #[test]
+    fn part_one_test() {
+        let input = SupplyStacks::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "CMZ",
+            SupplyStacks::part_one(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = SupplyStacks::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "MCD",
+            SupplyStacks::part_two(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+}
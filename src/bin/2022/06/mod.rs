@@ -0,0 +1,75 @@
+use std::{collections::HashSet, convert::Infallible, fmt::Display};
+
+use util::aoc::{Parameters, Solution};
+
+pub struct TuningTrouble;
+
+fn is_unique(elements: &[char]) -> bool {
+    let unique = HashSet::<char>::from_iter(elements.iter().copied());
+    elements.len() == unique.len()
+}
+
+fn solve(input: &[char], window_size: usize) -> usize {
+    let chars = input.iter().copied().enumerate().collect::<Vec<_>>();
+    let start_window = chars
+        .windows(window_size)
+        .find(|window| {
+            let values = window.iter().map(|(_, value)| *value).collect::<Vec<_>>();
+            is_unique(&values)
+        })
+        .unwrap();
+    let (index, _) = start_window.last().unwrap();
+    index + 1
+}
+
+impl Solution for TuningTrouble {
+    const YEAR: u16 = 2022;
+    const DAY: u16 = 6;
+
+    type Input = Vec<char>;
+    type ParseError = Infallible;
+    type SolveError = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        Ok(input.chars().collect())
+    }
+
+    fn part_one(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(solve(input, 4))
+    }
+
+    fn part_two(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(solve(input, 14))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../../res/2022/06/example.txt");
+
+    #[test]
+    fn part_one_test() {
+        let input = TuningTrouble::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "7",
+            TuningTrouble::part_one(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = TuningTrouble::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "19",
+            TuningTrouble::part_two(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+}
@@ -1,26 +1,26 @@
-use crate::coordinate::*;
+use util::geometry::Point;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Knot {
-    pub pos: Point,
+    pub pos: Point<i16>,
 }
 
 impl Knot {
     pub fn at_start() -> Self {
-        Knot { pos: Point(0, 0) }
+        Knot {
+            pos: Point::new(0, 0),
+        }
     }
 
     pub fn follow(&mut self, other: &Knot) {
-        let dif = other.pos - self.pos;
-        if !Self::is_adjacent(&dif) {
-            let approximation = dif.approximation();
-            self.pos += approximation;
+        if !Self::is_adjacent(self.pos, other.pos) {
+            let step = (other.pos - self.pos).signum();
+            self.pos += step;
         }
     }
 
-    fn is_adjacent(dif: &Vector) -> bool {
-        let adjacent_range = -1..=1;
-        adjacent_range.contains(&dif.0) && adjacent_range.contains(&dif.1)
+    fn is_adjacent(from: Point<i16>, to: Point<i16>) -> bool {
+        from.chebyshev(to) <= 1
     }
 }
 
@@ -33,30 +33,47 @@ mod tests {
 
     #[test]
     fn knot_at_start() {
-        assert_eq!(Knot { pos: Point(0, 0) }, Knot::at_start());
+        assert_eq!(
+            Knot {
+                pos: Point::new(0, 0)
+            },
+            Knot::at_start()
+        );
     }
 
     #[rstest]
-    #[case(Point(0, 0), helper::knot_follow(Point(0, 0), Point(0, 0)))]
-    #[case(Point(0, 0), helper::knot_follow(Point(0, 0), Point(1, 1)))]
-    #[case(Point(0, 0), helper::knot_follow(Point(0, 0), Point(-1, -1)))]
-    #[case(Point(0, 0), helper::knot_follow(Point(0, 0), Point(-1, 0)))]
-    #[case(Point(0, 0), helper::knot_follow(Point(0, 0), Point(0, 1)))]
-    #[case(Point(1, 1), helper::knot_follow(Point(0, 0), Point(1, 2)))]
-    #[case(Point(-1, -1), helper::knot_follow(Point(0, 0), Point(-2, -1)))]
-    fn knot_follow(#[case] expected: Point, #[case] actual: Point) {
+    #[case(
+        Point::new(0, 0),
+        helper::knot_follow(Point::new(0, 0), Point::new(0, 0))
+    )]
+    #[case(
+        Point::new(0, 0),
+        helper::knot_follow(Point::new(0, 0), Point::new(1, 1))
+    )]
+    #[case(Point::new(0, 0), helper::knot_follow(Point::new(0, 0), Point::new(-1, -1)))]
+    #[case(Point::new(0, 0), helper::knot_follow(Point::new(0, 0), Point::new(-1, 0)))]
+    #[case(
+        Point::new(0, 0),
+        helper::knot_follow(Point::new(0, 0), Point::new(0, 1))
+    )]
+    #[case(
+        Point::new(1, 1),
+        helper::knot_follow(Point::new(0, 0), Point::new(1, 2))
+    )]
+    #[case(Point::new(-1, -1), helper::knot_follow(Point::new(0, 0), Point::new(-2, -1)))]
+    fn knot_follow(#[case] expected: Point<i16>, #[case] actual: Point<i16>) {
         assert_eq!(expected, actual);
     }
 
     #[rstest]
-    #[case(Knot::is_adjacent(&Vector(1, 1)))]
-    #[case(Knot::is_adjacent(&Vector(-1, -1)))]
-    #[case(Knot::is_adjacent(&Vector(1, 0)))]
-    #[case(Knot::is_adjacent(&Vector(0, -1)))]
-    #[case(!Knot::is_adjacent(&Vector(2, 2)))]
-    #[case(!Knot::is_adjacent(&Vector(-2, -2)))]
-    #[case(!Knot::is_adjacent(&Vector(2, 0)))]
-    #[case(!Knot::is_adjacent(&Vector(0, -2)))]
+    #[case(Knot::is_adjacent(Point::new(0, 0), Point::new(1, 1)))]
+    #[case(Knot::is_adjacent(Point::new(0, 0), Point::new(-1, -1)))]
+    #[case(Knot::is_adjacent(Point::new(0, 0), Point::new(1, 0)))]
+    #[case(Knot::is_adjacent(Point::new(0, 0), Point::new(0, -1)))]
+    #[case(!Knot::is_adjacent(Point::new(0, 0), Point::new(2, 2)))]
+    #[case(!Knot::is_adjacent(Point::new(0, 0), Point::new(-2, -2)))]
+    #[case(!Knot::is_adjacent(Point::new(0, 0), Point::new(2, 0)))]
+    #[case(!Knot::is_adjacent(Point::new(0, 0), Point::new(0, -2)))]
     fn knot_is_adjacent(#[case] actual: bool) {
         assert_eq!(true, actual);
     }
@@ -64,7 +81,7 @@ mod tests {
     mod helper {
         use super::*;
 
-        pub fn knot_follow(follower_pos: Point, followee_pos: Point) -> Point {
+        pub fn knot_follow(follower_pos: Point<i16>, followee_pos: Point<i16>) -> Point<i16> {
             let mut follower = Knot { pos: follower_pos };
             let followee = Knot { pos: followee_pos };
             follower.follow(&followee);
@@ -0,0 +1,156 @@
+use std::{collections::HashSet, convert::Infallible, fmt::Display, io, str::FromStr};
+
+use motion::{Motion, MotionParseError};
+use rope::Rope;
+use snafu::prelude::*;
+use trail::Trail;
+use util::{
+    aoc::{Parameters, Solution},
+    geometry::Point,
+    image::ImageExport,
+};
+
+mod knot;
+mod motion;
+mod rope;
+mod trail;
+
+const SHORT_ROPE_LENGTH: usize = 2;
+const LONG_ROPE_LENGTH: usize = 10;
+
+pub struct RopeBridge;
+
+#[derive(Debug, PartialEq, Snafu)]
+#[snafu(display("Could not parse the motion in line {line_number}: {source}"))]
+pub struct MotionsParseError {
+    source: MotionParseError,
+    line_number: usize,
+}
+
+fn tail_positions(motions: &[Motion], length: usize) -> HashSet<Point<i16>> {
+    let mut rope = Rope::at_start(length);
+    let mut positions = HashSet::new();
+    for motion in motions {
+        rope.apply(motion, |rope| {
+            positions.insert(rope.tail().pos);
+        });
+    }
+    positions
+}
+
+/// Exports the trail of the tail as `<name>` and, if requested, the rope after every motion as
+/// frames.
+fn export(motions: &[Motion], length: usize, export: &ImageExport, name: &str) -> io::Result<()> {
+    let mut frames = export.frames(name)?;
+    let mut rope = Rope::at_start(length);
+    let mut trail = Trail::new(motions);
+    trail.mark(rope.tail());
+    for motion in motions {
+        rope.apply(motion, |rope| trail.mark(rope.tail()));
+        if let Some(frames) = &mut frames {
+            frames.push(&trail.image(rope.knots()))?;
+        }
+    }
+    export.save(name, &trail.image(rope.knots()))?;
+    Ok(())
+}
+
+impl Solution for RopeBridge {
+    const YEAR: u16 = 2022;
+    const DAY: u16 = 9;
+
+    type Input = Vec<Motion>;
+    type ParseError = MotionsParseError;
+    type SolveError = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                Motion::from_str(line).context(MotionsParseSnafu {
+                    line_number: index + 1,
+                })
+            })
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(tail_positions(input, SHORT_ROPE_LENGTH).len())
+    }
+
+    fn part_two(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        Ok(tail_positions(input, LONG_ROPE_LENGTH).len())
+    }
+
+    fn export_images(input: &Self::Input, image_export: &ImageExport) -> io::Result<()> {
+        export(input, SHORT_ROPE_LENGTH, image_export, "short_rope")?;
+        export(input, LONG_ROPE_LENGTH, image_export, "long_rope")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../../res/2022/09/example.txt");
+
+    #[test]
+    fn parse_test_err() {
+        let expected = MotionsParseError {
+            source: MotionParseError::MalformedMotion {
+                string: "R".to_string(),
+            },
+            line_number: 2,
+        };
+
+        assert_eq!(expected, RopeBridge::parse("U 1\nR").unwrap_err());
+    }
+
+    #[test]
+    fn part_one_test() {
+        let input = RopeBridge::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "13",
+            RopeBridge::part_one(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = RopeBridge::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "1",
+            RopeBridge::part_two(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn export_images_test() {
+        let input = RopeBridge::parse(EXAMPLE).unwrap();
+        let directory = std::env::temp_dir().join(format!("rope-{}", std::process::id()));
+        let parameters = Parameters::new()
+            .with("images", directory.to_str().unwrap())
+            .with("frames", "true");
+        let export = ImageExport::from_parameters(&parameters).unwrap().unwrap();
+
+        RopeBridge::export_images(&input, &export).unwrap();
+
+        assert!(directory.join("short_rope.ppm").is_file());
+        assert!(directory.join("long_rope.ppm").is_file());
+        // One frame per motion.
+        assert_eq!(
+            input.len(),
+            std::fs::read_dir(directory.join("long_rope"))
+                .unwrap()
+                .count()
+        );
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
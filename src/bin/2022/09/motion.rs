@@ -1,6 +1,17 @@
-use std::str::FromStr;
+use std::{num::ParseIntError, str::FromStr};
 
-use crate::direction::Direction;
+use snafu::prelude::*;
+use util::geometry::Direction;
+
+#[derive(Debug, PartialEq, Snafu)]
+pub enum MotionParseError {
+    #[snafu(display("Expected a direction and a count separated by a space in \"{string}\"."))]
+    MalformedMotion { string: String },
+    #[snafu(display("\"{string}\" is not a direction."))]
+    InvalidDirection { string: String },
+    #[snafu(display("Could not parse the count: {source}"))]
+    InvalidCount { source: ParseIntError },
+}
 
 #[derive(PartialEq, Debug)]
 pub struct Motion {
@@ -9,19 +20,29 @@ pub struct Motion {
 }
 
 impl FromStr for Motion {
-    type Err = ();
+    type Err = MotionParseError;
 
     fn from_str(string: &str) -> Result<Self, Self::Err> {
         if let [dir, count] = string.split(' ').collect::<Vec<_>>().as_slice() {
-            let dir = Direction::from_str(dir)?;
-            let count: u8 = count.parse().map_err(|_| ())?;
+            let dir = parse_direction(dir)?;
+            let count: u8 = count.parse().context(InvalidCountSnafu)?;
             Ok(Motion { dir, count })
         } else {
-            Err(())
+            MalformedMotionSnafu { string }.fail()
         }
     }
 }
 
+fn parse_direction(string: &str) -> Result<Direction, MotionParseError> {
+    match string {
+        "U" => Ok(Direction::North),
+        "D" => Ok(Direction::South),
+        "R" => Ok(Direction::East),
+        "L" => Ok(Direction::West),
+        _ => InvalidDirectionSnafu { string }.fail(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
@@ -30,16 +51,47 @@ mod tests {
     use super::*;
 
     #[rstest]
-    #[case(Ok(Motion {dir: Direction::Up, count: 1 }), Motion::from_str("U 1"))]
-    #[case(Ok(Motion {dir: Direction::Down, count: 2 }), Motion::from_str("D 2"))]
-    #[case(Ok(Motion {dir: Direction::Right, count: 3 }), Motion::from_str("R 3"))]
-    #[case(Ok(Motion {dir: Direction::Left, count: 4 }), Motion::from_str("L 4"))]
-    #[case(Err(()), Motion::from_str("X 5"))]
-    #[case(Err(()), Motion::from_str("U -5"))]
-    #[case(Err(()), Motion::from_str("U"))]
-    #[case(Err(()), Motion::from_str("5"))]
-    #[case(Err(()), Motion::from_str(""))]
-    fn motion_from_str(#[case] expected: Result<Motion, ()>, #[case] actual: Result<Motion, ()>) {
+    #[case(Ok(Motion {dir: Direction::North, count: 1 }), Motion::from_str("U 1"))]
+    #[case(Ok(Motion {dir: Direction::South, count: 2 }), Motion::from_str("D 2"))]
+    #[case(Ok(Motion {dir: Direction::East, count: 3 }), Motion::from_str("R 3"))]
+    #[case(Ok(Motion {dir: Direction::West, count: 4 }), Motion::from_str("L 4"))]
+    #[case(
+        Err(MotionParseError::InvalidDirection { string: "X".to_string() }),
+        Motion::from_str("X 5")
+    )]
+    #[case(
+        Err(MotionParseError::InvalidCount { source: "-5".parse::<u8>().unwrap_err() }),
+        Motion::from_str("U -5")
+    )]
+    #[case(
+        Err(MotionParseError::MalformedMotion { string: "U".to_string() }),
+        Motion::from_str("U")
+    )]
+    #[case(
+        Err(MotionParseError::MalformedMotion { string: "5".to_string() }),
+        Motion::from_str("5")
+    )]
+    #[case(
+        Err(MotionParseError::MalformedMotion { string: "".to_string() }),
+        Motion::from_str("")
+    )]
+    fn motion_from_str(
+        #[case] expected: Result<Motion, MotionParseError>,
+        #[case] actual: Result<Motion, MotionParseError>,
+    ) {
         assert_eq!(expected, actual);
     }
+
+    #[rstest]
+    #[case(Ok(Direction::North), "U")]
+    #[case(Ok(Direction::South), "D")]
+    #[case(Ok(Direction::East), "R")]
+    #[case(Ok(Direction::West), "L")]
+    #[case(Err(MotionParseError::InvalidDirection { string: "X".to_string() }), "X")]
+    fn parse_direction_test(
+        #[case] expected: Result<Direction, MotionParseError>,
+        #[case] string: &str,
+    ) {
+        assert_eq!(expected, parse_direction(string));
+    }
 }
@@ -0,0 +1,62 @@
+use crate::y2022::d09::{knot::Knot, motion::Motion};
+
+/// Knots that each follow the one before them, starting with the head.
+pub struct Rope(Vec<Knot>);
+
+impl Rope {
+    pub fn at_start(length: usize) -> Self {
+        Self(vec![Knot::at_start(); length])
+    }
+
+    pub fn knots(&self) -> &[Knot] {
+        &self.0
+    }
+
+    pub fn tail(&self) -> &Knot {
+        self.0.last().expect("A rope has at least one knot.")
+    }
+
+    /// Moves the head step by step and passes the rope to `visit` after every step.
+    pub fn apply(&mut self, motion: &Motion, mut visit: impl FnMut(&Self)) {
+        let step = motion.dir.vector();
+        for _ in 0..motion.count {
+            self.0[0].pos += step;
+            for index in 1..self.0.len() {
+                let leader = self.0[index - 1];
+                self.0[index].follow(&leader);
+            }
+            visit(self);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use util::geometry::{Direction, Point};
+
+    use super::*;
+
+    #[test]
+    fn apply_test() {
+        let mut rope = Rope::at_start(3);
+        let mut tails = Vec::new();
+
+        rope.apply(
+            &Motion {
+                dir: Direction::East,
+                count: 3,
+            },
+            |rope| tails.push(rope.tail().pos),
+        );
+
+        assert_eq!(
+            vec![Point::new(0, 0), Point::new(0, 0), Point::new(1, 0)],
+            tails
+        );
+        assert_eq!(
+            [Point::new(3, 0), Point::new(2, 0), Point::new(1, 0)],
+            [0, 1, 2].map(|index| rope.knots()[index].pos)
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use util::{
+    geometry::Point,
+    grid::{Grid, Position},
+    image::{Image, Rgb},
+};
+
+use crate::y2022::d09::{knot::Knot, motion::Motion};
+
+const BACKGROUND: Rgb = Rgb(255, 255, 255);
+const TRAIL: Rgb = Rgb(255, 0, 0);
+const HEAD: Rgb = Rgb(0, 200, 0);
+const KNOT: Rgb = Rgb(0, 0, 255);
+
+/// The positions visited by the tail of a rope, drawn onto the area the head moves in. The other
+/// knots always stay within that area, since each one only steps towards the one before it.
+pub struct Trail {
+    min: Point<i16>,
+    max: Point<i16>,
+    pixels: Grid<Rgb>,
+}
+
+impl Trail {
+    pub fn new(motions: &[Motion]) -> Self {
+        let mut head = Point::new(0, 0);
+        let (mut min, mut max) = (head, head);
+        for motion in motions {
+            for _ in 0..motion.count {
+                head += motion.dir.vector();
+                min = Point::new(min.x.min(head.x), min.y.min(head.y));
+                max = Point::new(max.x.max(head.x), max.y.max(head.y));
+            }
+        }
+
+        let width = (max.x - min.x) as usize + 1;
+        let height = (max.y - min.y) as usize + 1;
+        Self {
+            min,
+            max,
+            pixels: Grid::filled(width, height, BACKGROUND),
+        }
+    }
+
+    pub fn mark(&mut self, tail: &Knot) {
+        let position = self.position(tail.pos);
+        self.pixels[position] = TRAIL;
+    }
+
+    /// The trail with the `knots` of a rope on top, its head in green and the others in blue.
+    pub fn image(&self, knots: &[Knot]) -> Image {
+        let mut pixels = self.pixels.clone();
+        for (index, knot) in knots.iter().enumerate().rev() {
+            pixels[self.position(knot.pos)] = if index == 0 { HEAD } else { KNOT };
+        }
+        Image::Pixmap(pixels)
+    }
+
+    /// Flips the y axis, since it points up for knots but down for pixels.
+    fn position(&self, point: Point<i16>) -> Position {
+        Position::new(
+            (point.x - self.min.x) as usize,
+            (self.max.y - point.y) as usize,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use util::geometry::Direction;
+
+    use super::*;
+
+    #[test]
+    fn image_test() {
+        let motions = [
+            Motion {
+                dir: Direction::East,
+                count: 2,
+            },
+            Motion {
+                dir: Direction::North,
+                count: 1,
+            },
+        ];
+        let mut trail = Trail::new(&motions);
+        trail.mark(&Knot::at_start());
+
+        let head = Knot {
+            pos: Point::new(2, 1),
+        };
+        let tail = Knot {
+            pos: Point::new(1, 0),
+        };
+        let Image::Pixmap(pixels) = trail.image(&[head, tail]) else {
+            panic!("Trail image should be a pixmap.");
+        };
+
+        assert_eq!((3, 2), (pixels.width(), pixels.height()));
+        assert_eq!(HEAD, pixels[Position::new(2, 0)]);
+        assert_eq!(KNOT, pixels[Position::new(1, 1)]);
+        assert_eq!(TRAIL, pixels[Position::new(0, 1)]);
+        assert_eq!(BACKGROUND, pixels[Position::new(0, 0)]);
+    }
+}
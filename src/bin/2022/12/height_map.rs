@@ -0,0 +1,114 @@
+use std::{collections::HashSet, fmt::Display};
+
+use snafu::prelude::*;
+use util::{
+    grid::{Connectivity, Grid, GridParseError, Position},
+    image::{Image, Rgb},
+};
+
+use super::*;
+
+#[derive(Debug, PartialEq, Snafu)]
+pub enum HeightMapParseError {
+    #[snafu(display("Could not parse the height map: {source}"))]
+    InvalidGrid {
+        source: GridParseError<TileParseError>,
+    },
+    #[snafu(display("The height map has no start."))]
+    MissingStart,
+    #[snafu(display("The height map has no end."))]
+    MissingEnd,
+}
+
+pub struct HeightMap {
+    grid: Grid<Tile>,
+    start: Position,
+    end: Position,
+}
+
+impl HeightMap {
+    pub fn parse(input: &str) -> Result<Self, HeightMapParseError> {
+        let grid = Grid::parse(input, |_, char| Tile::parse(char)).context(InvalidGridSnafu)?;
+        let find = |wanted: Tile| {
+            grid.iter()
+                .find_map(|(position, tile)| (*tile == wanted).then_some(position))
+        };
+        let start = find(Tile::Start).context(MissingStartSnafu)?;
+        let end = find(Tile::End).context(MissingEndSnafu)?;
+
+        Ok(Self { grid, start, end })
+    }
+
+    pub fn start(&self) -> &Position {
+        &self.start
+    }
+
+    pub fn end(&self) -> &Position {
+        &self.end
+    }
+
+    pub fn positions<F>(&self, mut f: F) -> Vec<Position>
+    where
+        F: FnMut(&Tile) -> bool,
+    {
+        self.grid
+            .iter()
+            .filter_map(|(position, tile)| f(tile).then_some(position))
+            .collect()
+    }
+
+    pub fn valid_neighbors(&self, from: &Position) -> Vec<Position> {
+        let from_tile = self
+            .grid
+            .get(*from)
+            .unwrap_or_else(|| panic!("Current tile at position `{:?}` cannot be found.", from));
+
+        self.grid
+            .neighbors(*from, Connectivity::Four)
+            .filter(|to| from_tile.is_step_possible(&self.grid[*to]))
+            .collect()
+    }
+
+    /// The elevation of every tile in shades of gray, with the start in green, the end in blue,
+    /// the `highlighted` tiles in yellow and the other `visited` tiles in red.
+    pub fn status_image(
+        &self,
+        visited: &HashSet<Position>,
+        highlighted: &HashSet<Position>,
+    ) -> Image {
+        let Elevation(highest) = Tile::End.elevation();
+        Image::Pixmap(Grid::from_fn(
+            self.grid.width(),
+            self.grid.height(),
+            |position| match self.grid[position] {
+                Tile::Start => Rgb(0, 200, 0),
+                Tile::End => Rgb(0, 0, 255),
+                _ if highlighted.contains(&position) => Rgb(255, 200, 0),
+                _ if visited.contains(&position) => Rgb(255, 0, 0),
+                Tile::Normal(Elevation(elevation)) => {
+                    let gray = (elevation as u16 * 255 / highest as u16) as u8;
+                    Rgb(gray, gray, gray)
+                }
+            },
+        ))
+    }
+}
+
+/// Renders the elevation of every tile as a table, with the start and end marked.
+impl Display for HeightMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.grid.rows() {
+            write!(f, "|")?;
+            for tile in row {
+                let elevation = match tile {
+                    Tile::Normal(Elevation(elevation)) => format!("{:0>2}", elevation),
+                    Tile::Start => "SS".to_string(),
+                    Tile::End => "EE".to_string(),
+                };
+                write!(f, "{}|", elevation)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,266 @@
+use std::{collections::HashSet, fmt::Display, io};
+
+use height_map::*;
+use snafu::prelude::*;
+use tile::*;
+use util::{
+    aoc::{Parameters, Solution},
+    grid::Position,
+    image::ImageExport,
+    search::{self, BfsLayer, Path},
+};
+
+mod height_map;
+mod tile;
+
+pub struct HillClimbingAlgorithm;
+
+#[derive(Debug, PartialEq, Snafu)]
+#[snafu(display("The end is unreachable."))]
+pub struct UnreachableEndError;
+
+const LOWEST: char = 'a';
+const HIGHEST: char = 'z';
+const START: char = 'S';
+const END: char = 'E';
+
+/// Every step between two tiles costs the same, so the Manhattan distance never overestimates.
+fn start_to_end(height_map: &HeightMap) -> Option<Path<Position>> {
+    let end = *height_map.end();
+
+    search::a_star(
+        [*height_map.start()],
+        |position| *position == end,
+        |position| {
+            height_map
+                .valid_neighbors(position)
+                .into_iter()
+                .map(|neighbor| (neighbor, 1))
+        },
+        |position| position.manhattan(end),
+    )
+}
+
+fn lowest_to_end(height_map: &HeightMap) -> Option<Path<Position>> {
+    explore(height_map, lowest_positions(height_map), |_| {})
+}
+
+fn lowest_positions(height_map: &HeightMap) -> Vec<Position> {
+    height_map.positions(|tile| tile.elevation() == Elevation(0))
+}
+
+/// Searches the end with a BFS from `starts`, passing every layer of the search to `visit`.
+fn explore(
+    height_map: &HeightMap,
+    starts: impl IntoIterator<Item = Position>,
+    visit: impl FnMut(&BfsLayer<Position>),
+) -> Option<Path<Position>> {
+    let end = *height_map.end();
+
+    search::bfs_visiting(
+        starts,
+        |position| *position == end,
+        |position| height_map.valid_neighbors(position),
+        visit,
+    )
+}
+
+/// Exports the tiles visited by a BFS from `starts` together with the path as `<name>` and, if
+/// requested, every layer of the search with its frontier as frames.
+fn export(
+    height_map: &HeightMap,
+    starts: impl IntoIterator<Item = Position>,
+    export: &ImageExport,
+    name: &str,
+) -> io::Result<()> {
+    let mut frames = export.frames(name)?;
+    let mut frames_result = Ok(());
+    let mut visited = HashSet::new();
+    let path = explore(height_map, starts, |layer| {
+        visited.extend(layer.visited());
+        if let (Some(frames), Ok(())) = (&mut frames, &frames_result) {
+            let frontier = layer.frontier.iter().copied().collect();
+            frames_result = frames
+                .push(&height_map.status_image(&visited, &frontier))
+                .map(|_| ());
+        }
+    });
+    frames_result?;
+
+    let nodes = path.map(|path| path.nodes).unwrap_or_default();
+    let image = height_map.status_image(&visited, &nodes.into_iter().collect());
+    export.save(name, &image)?;
+    Ok(())
+}
+
+fn steps(path: Option<Path<Position>>) -> Result<usize, UnreachableEndError> {
+    Ok(path.context(UnreachableEndSnafu)?.steps())
+}
+
+impl Solution for HillClimbingAlgorithm {
+    const YEAR: u16 = 2022;
+    const DAY: u16 = 12;
+
+    type Input = HeightMap;
+    type ParseError = HeightMapParseError;
+    type SolveError = UnreachableEndError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        HeightMap::parse(input)
+    }
+
+    fn part_one(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        steps(start_to_end(input))
+    }
+
+    fn part_two(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+        steps(lowest_to_end(input))
+    }
+
+    fn export_images(input: &Self::Input, image_export: &ImageExport) -> io::Result<()> {
+        export(input, [*input.start()], image_export, "start_to_end")?;
+        export(
+            input,
+            lowest_positions(input),
+            image_export,
+            "lowest_to_end",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use util::image::{Image, Rgb};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../../res/2022/12/example.txt");
+
+    #[test]
+    fn part_one_test() {
+        let input = HillClimbingAlgorithm::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "31",
+            HillClimbingAlgorithm::part_one(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = HillClimbingAlgorithm::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            "29",
+            HillClimbingAlgorithm::part_two(&input, &Parameters::new())
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn start_to_end_test_path() {
+        let height_map = HillClimbingAlgorithm::parse(EXAMPLE).unwrap();
+
+        let path = start_to_end(&height_map).unwrap();
+
+        assert_eq!(height_map.start(), path.start());
+        assert_eq!(height_map.end(), path.end());
+        for window in path.nodes.windows(2) {
+            assert!(height_map.valid_neighbors(&window[0]).contains(&window[1]));
+        }
+    }
+
+    #[test]
+    fn start_to_end_test_unreachable() {
+        let height_map = HillClimbingAlgorithm::parse("Sbcz\nabzE").unwrap();
+
+        assert_eq!(None, start_to_end(&height_map));
+        assert_eq!(
+            Some(UnreachableEndError),
+            HillClimbingAlgorithm::part_one(&height_map, &Parameters::new()).err()
+        );
+    }
+
+    #[test]
+    fn trait_display_fmt_test() {
+        let height_map = HillClimbingAlgorithm::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            Some("|SS|00|01|16|15|14|13|12|"),
+            height_map.to_string().lines().next()
+        );
+    }
+
+    #[test]
+    fn parse_test_err() {
+        assert_eq!(
+            Err(HeightMapParseError::MissingEnd),
+            HeightMap::parse("Sab\nabc").map(|_| ())
+        );
+        assert!(matches!(
+            HeightMap::parse("Sab\naEc\na").map(|_| ()),
+            Err(HeightMapParseError::InvalidGrid { .. })
+        ));
+        assert!(HeightMap::parse("Sa?\nabE").is_err());
+    }
+
+    #[test]
+    fn export_images_test() {
+        let height_map = HillClimbingAlgorithm::parse(EXAMPLE).unwrap();
+        let directory = std::env::temp_dir().join(format!("hills-{}", std::process::id()));
+        let parameters = Parameters::new()
+            .with("images", directory.to_str().unwrap())
+            .with("frames", "true");
+        let export = ImageExport::from_parameters(&parameters).unwrap().unwrap();
+
+        HillClimbingAlgorithm::export_images(&height_map, &export).unwrap();
+
+        assert!(directory.join("start_to_end.ppm").is_file());
+        // One frame per layer of the search, the end is 31 steps away from the start.
+        assert_eq!(
+            32,
+            std::fs::read_dir(directory.join("start_to_end"))
+                .unwrap()
+                .count()
+        );
+        assert!(directory.join("lowest_to_end/00000.ppm").is_file());
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn export_images_test_err() {
+        let height_map = HillClimbingAlgorithm::parse(EXAMPLE).unwrap();
+        let file = std::env::temp_dir().join(format!("hills-file-{}", std::process::id()));
+        std::fs::write(&file, "").unwrap();
+        let parameters = Parameters::new().with("images", file.to_str().unwrap());
+        let export = ImageExport::from_parameters(&parameters).unwrap().unwrap();
+
+        assert!(HillClimbingAlgorithm::export_images(&height_map, &export).is_err());
+        std::fs::remove_file(file).unwrap();
+    }
+
+    #[test]
+    fn height_map_status_image_test() {
+        let height_map = HillClimbingAlgorithm::parse("Sbz\nayE").unwrap();
+        let visited = HashSet::from([
+            Position::new(1, 0),
+            Position::new(0, 0),
+            Position::new(1, 1),
+        ]);
+        let highlighted = HashSet::from([Position::new(1, 1)]);
+
+        let Image::Pixmap(pixels) = height_map.status_image(&visited, &highlighted) else {
+            panic!("Status image should be a pixmap.");
+        };
+
+        assert_eq!(Rgb(0, 200, 0), pixels[Position::new(0, 0)]);
+        assert_eq!(Rgb(255, 0, 0), pixels[Position::new(1, 0)]);
+        assert_eq!(Rgb(255, 255, 255), pixels[Position::new(2, 0)]);
+        assert_eq!(Rgb(0, 0, 0), pixels[Position::new(0, 1)]);
+        assert_eq!(Rgb(255, 200, 0), pixels[Position::new(1, 1)]);
+        assert_eq!(Rgb(0, 0, 255), pixels[Position::new(2, 1)]);
+    }
+}
@@ -1,21 +1,38 @@
+use snafu::prelude::*;
+
 use super::*;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Debug, Clone, PartialEq, Snafu)]
+#[snafu(display("Char '{invalid_char}' is not a tile."))]
+pub struct TileParseError {
+    invalid_char: char,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Elevation(pub u8);
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Tile {
     Normal(Elevation),
-    Start(Coordinate),
-    End(Coordinate),
+    Start,
+    End,
 }
 
 impl Tile {
+    pub fn parse(char: char) -> Result<Self, TileParseError> {
+        match char {
+            START => Ok(Tile::Start),
+            END => Ok(Tile::End),
+            LOWEST..=HIGHEST => Ok(Tile::Normal(Elevation(char as u8 - LOWEST as u8))),
+            _ => TileParseSnafu { invalid_char: char }.fail(),
+        }
+    }
+
     pub fn elevation(&self) -> Elevation {
         Elevation(match self {
             Tile::Normal(Elevation(elevation)) => *elevation,
-            Tile::Start(_) => 0,
-            Tile::End(_) => HIGHEST - LOWEST,
+            Tile::Start => 0,
+            Tile::End => HIGHEST as u8 - LOWEST as u8,
         })
     }
 
@@ -0,0 +1,105 @@
+use std::ops::RangeInclusive;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    ranges: Vec<RangeInclusive<i32>>,
+}
+
+impl Coverage {
+    /// Sorts the ranges and merges the ones that overlap or touch each other.
+    pub fn from_ranges(ranges: impl IntoIterator<Item = RangeInclusive<i32>>) -> Self {
+        let mut sorted = ranges.into_iter().collect::<Vec<_>>();
+        sorted.sort_unstable_by_key(|range| *range.start());
+
+        let mut ranges: Vec<RangeInclusive<i32>> = Vec::with_capacity(sorted.len());
+        for range in sorted {
+            match ranges.last_mut() {
+                Some(last) if *range.start() <= last.end().saturating_add(1) => {
+                    if range.end() > last.end() {
+                        *last = *last.start()..=*range.end();
+                    }
+                }
+                _ => ranges.push(range),
+            }
+        }
+
+        Self { ranges }
+    }
+
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|range| (*range.end() as i64 - *range.start() as i64 + 1) as u64)
+            .sum()
+    }
+
+    pub fn contains(&self, value: i32) -> bool {
+        self.ranges.iter().any(|range| range.contains(&value))
+    }
+
+    /// Returns the smallest value within `bounds` that is not covered.
+    pub fn first_gap(&self, bounds: RangeInclusive<i32>) -> Option<i32> {
+        let mut candidate = *bounds.start();
+        for range in &self.ranges {
+            if candidate > *bounds.end() {
+                return None;
+            }
+            if range.contains(&candidate) {
+                candidate = range.end().checked_add(1)?;
+            } else if *range.start() > candidate {
+                break;
+            }
+        }
+        bounds.contains(&candidate).then_some(candidate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(vec![5..=7, 1..=3], vec![1..=3, 5..=7])]
+    #[case(vec![1..=4, 3..=6], vec![1..=6])]
+    #[case(vec![1..=3, 4..=6], vec![1..=6])]
+    #[case(vec![1..=10, 2..=3, 4..=5], vec![1..=10])]
+    fn from_ranges_test(
+        #[case] ranges: Vec<RangeInclusive<i32>>,
+        #[case] expected: Vec<RangeInclusive<i32>>,
+    ) {
+        assert_eq!(Coverage { ranges: expected }, Coverage::from_ranges(ranges));
+    }
+
+    #[test]
+    fn len_test() {
+        let coverage = Coverage::from_ranges([-2..=2, 12..=12, 0..=4]);
+
+        assert_eq!(8, coverage.len());
+    }
+
+    #[rstest]
+    #[case(-3, false)]
+    #[case(-2, true)]
+    #[case(5, false)]
+    #[case(12, true)]
+    fn contains_test(#[case] value: i32, #[case] expected: bool) {
+        let coverage = Coverage::from_ranges([-2..=4, 12..=12]);
+
+        assert_eq!(expected, coverage.contains(value));
+    }
+
+    #[rstest]
+    #[case(vec![0..=20], None)]
+    #[case(vec![-5..=13, 15..=25], Some(14))]
+    #[case(vec![2..=20], Some(0))]
+    #[case(vec![0..=19], Some(20))]
+    #[case(vec![-3..=-1, 3..=30], Some(0))]
+    fn first_gap_test(#[case] ranges: Vec<RangeInclusive<i32>>, #[case] expected: Option<i32>) {
+        let coverage = Coverage::from_ranges(ranges);
+
+        assert_eq!(expected, coverage.first_gap(0..=20));
+    }
+}
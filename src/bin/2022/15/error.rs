@@ -0,0 +1,23 @@
+use snafu::prelude::*;
+use util::aoc::InvalidParameterError;
+
+use crate::y2022::d15::sensor_beacon_pair::SensorBeaconPairParseError;
+
+#[derive(Debug, Clone, PartialEq, Snafu)]
+#[snafu(
+    display("Could not parse line {line} of the report.\n{source}"),
+    visibility(pub)
+)]
+pub struct ReportParseError {
+    line: usize,
+    source: SensorBeaconPairParseError,
+}
+
+#[derive(Debug, Clone, PartialEq, Snafu)]
+#[snafu(visibility(pub))]
+pub enum BeaconSearchError {
+    #[snafu(display("{source}"), context(false))]
+    InvalidParameter { source: InvalidParameterError },
+    #[snafu(display("No position could be found that is not covered by a sensor."))]
+    NoDistressBeacon,
+}
@@ -0,0 +1,179 @@
+use std::{collections::HashSet, fmt::Display, ops::RangeInclusive};
+
+use snafu::{OptionExt, ResultExt};
+
+use util::{
+    aoc::{Parameters, Solution},
+    geometry::Point,
+};
+
+mod coverage;
+mod error;
+mod sensor_beacon_pair;
+
+use coverage::*;
+use error::*;
+use sensor_beacon_pair::*;
+
+/// Row that is inspected if the `row` parameter is not set. The examples use row 10 instead.
+const DEFAULT_ROW: i32 = 2_000_000;
+/// Upper bound of both coordinates of the distress beacon if the `max_coordinate` parameter is
+/// not set. The examples use 20 instead.
+const DEFAULT_MAX_COORDINATE: i32 = 4_000_000;
+const TUNING_FREQUENCY_FACTOR: i64 = 4_000_000;
+
+pub struct BeaconExclusionZone;
+
+fn impossibles_for_line(pair: SensorBeaconPair, line: i32) -> Option<RangeInclusive<i32>> {
+    let SensorBeaconPair {
+        sensor: Point { x, y },
+        ..
+    } = pair;
+    let manhattan = pair.manhattan_between();
+
+    let dy = y.abs_diff(line);
+    if dy > manhattan {
+        return None;
+    }
+
+    let side = (manhattan - dy) as i32;
+    Some(x - side..=x + side)
+}
+
+fn coverage_of_line(input: &[SensorBeaconPair], line: i32) -> Coverage {
+    Coverage::from_ranges(
+        input
+            .iter()
+            .filter_map(|pair| impossibles_for_line(*pair, line)),
+    )
+}
+
+fn impossible_positions(input: &[SensorBeaconPair], line: i32) -> u64 {
+    let coverage = coverage_of_line(input, line);
+
+    let beacons = input
+        .iter()
+        .map(|pair| pair.beacon)
+        .filter(|beacon| beacon.y == line && coverage.contains(beacon.x))
+        .collect::<HashSet<_>>();
+
+    coverage.len() - beacons.len() as u64
+}
+
+fn tuning_frequency(input: &[SensorBeaconPair], max: i32) -> Result<i64, BeaconSearchError> {
+    (0..=max)
+        .find_map(|y| {
+            let x = coverage_of_line(input, y).first_gap(0..=max)?;
+            Some(x as i64 * TUNING_FREQUENCY_FACTOR + y as i64)
+        })
+        .context(NoDistressBeaconSnafu)
+}
+
+impl Solution for BeaconExclusionZone {
+    const YEAR: u16 = 2022;
+    const DAY: u16 = 15;
+
+    type Input = Vec<SensorBeaconPair>;
+    type ParseError = ReportParseError;
+    type SolveError = BeaconSearchError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| line.parse().context(ReportParseSnafu { line: index + 1 }))
+            .collect()
+    }
+
+    fn part_one(
+        input: &Self::Input,
+        parameters: &Parameters,
+    ) -> Result<impl Display, Self::SolveError> {
+        let line = parameters.get_or("row", DEFAULT_ROW)?;
+        Ok(impossible_positions(input, line))
+    }
+
+    fn part_two(
+        input: &Self::Input,
+        parameters: &Parameters,
+    ) -> Result<impl Display, Self::SolveError> {
+        let max = parameters.get_or("max_coordinate", DEFAULT_MAX_COORDINATE)?;
+        tuning_frequency(input, max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use util::aoc::InvalidParameterError;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../../res/2022/15/example.txt");
+
+    #[test]
+    fn part_one_test() {
+        let input = BeaconExclusionZone::parse(EXAMPLE).unwrap();
+        let parameters = Parameters::new().with("row", 10);
+
+        assert_eq!(
+            "26",
+            BeaconExclusionZone::part_one(&input, &parameters)
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn part_one_test_invalid_row() {
+        let input = BeaconExclusionZone::parse(EXAMPLE).unwrap();
+        let parameters = Parameters::new().with("row", "ten");
+
+        let expected = InvalidParameterError {
+            key: "row".to_string(),
+            value: "ten".to_string(),
+        };
+
+        assert_eq!(
+            Some(BeaconSearchError::InvalidParameter { source: expected }),
+            BeaconExclusionZone::part_one(&input, &parameters).err()
+        );
+    }
+
+    #[test]
+    fn part_two_test_no_distress_beacon() {
+        let input = BeaconExclusionZone::parse("Sensor at x=0, y=0: closest beacon is at x=2, y=2")
+            .unwrap();
+        let parameters = Parameters::new().with("max_coordinate", 2);
+
+        assert_eq!(
+            Some(BeaconSearchError::NoDistressBeacon),
+            BeaconExclusionZone::part_two(&input, &parameters).err()
+        );
+    }
+
+    #[test]
+    fn parse_test_err() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\nSensor at x=9 y=16: closest beacon is at x=10, y=16";
+
+        let error = BeaconExclusionZone::parse(input).unwrap_err();
+
+        let expected = [
+            "Could not parse line 2 of the report.",
+            "Could not parse sensor and beacon at column 14.",
+        ];
+        assert_eq!(expected.join("\n"), error.to_string());
+    }
+
+    #[test]
+    fn part_two_test() {
+        let input = BeaconExclusionZone::parse(EXAMPLE).unwrap();
+        let parameters = Parameters::new().with("max_coordinate", 20);
+
+        assert_eq!(
+            "56000011",
+            BeaconExclusionZone::part_two(&input, &parameters)
+                .unwrap()
+                .to_string()
+        );
+    }
+}
@@ -1,39 +1,74 @@
-use crate::coordinate::Coordinate;
+mod parsing;
 
-#[derive(Debug, Clone, Copy)]
-pub struct SensorBeaconPair {
-    pub sensor: Coordinate,
-    pub beacon: Coordinate,
-}
+use std::str::FromStr;
 
-impl SensorBeaconPair {
-    pub fn from_input_line(line: &str) -> Self {
-        let parts = line.split(' ');
+use nom::{combinator::all_consuming, Finish};
 
-        let mut skipped = parts.skip(2);
-        let sensor = parse_location(skipped.next().unwrap(), skipped.next().unwrap());
+use util::geometry::Point;
 
-        let mut skipped = skipped.skip(4);
-        let beacon = parse_beacon(skipped.next().unwrap(), skipped.next().unwrap());
+pub use self::parsing::SensorBeaconPairParseError;
 
-        Self { sensor, beacon }
-    }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SensorBeaconPair {
+    pub sensor: Point<i32>,
+    pub beacon: Point<i32>,
+}
 
+impl SensorBeaconPair {
     pub fn manhattan_between(&self) -> u32 {
         self.sensor.manhattan(self.beacon)
     }
 }
 
-fn parse_location(x: &str, y: &str) -> Coordinate {
-    let x = x[2..x.len() - 1].parse().unwrap();
-    let y = y[2..y.len() - 1].parse().unwrap();
+impl FromStr for SensorBeaconPair {
+    type Err = SensorBeaconPairParseError;
 
-    Coordinate { x, y }
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        all_consuming(parsing::parse_sensor_beacon_pair)(string)
+            .finish()
+            .map(|(_, output)| output)
+            .map_err(|error| SensorBeaconPairParseError::with_parse_context(string, error))
+    }
 }
 
-fn parse_beacon(x: &str, y: &str) -> Coordinate {
-    let x = x[2..x.len() - 1].parse().unwrap();
-    let y = y[2..].parse().unwrap();
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
 
-    Coordinate { x, y }
+    #[test]
+    fn from_str_test_ok() {
+        let expected = SensorBeaconPair {
+            sensor: Point::new(8, 7),
+            beacon: Point::new(2, 10),
+        };
+
+        assert_eq!(
+            Ok(expected),
+            "Sensor at x=8, y=7: closest beacon is at x=2, y=10".parse()
+        );
+    }
+
+    #[rstest]
+    #[case("Sensor at x=8, y=7: closest beacon is at x=2, y=10 ", 51)]
+    #[case("Sensor at x=8, y=7 closest beacon is at x=2, y=10", 19)]
+    #[case("Sensor at x=8, y=: closest beacon is at x=2, y=10", 18)]
+    #[case("Beacon at x=8, y=7: closest beacon is at x=2, y=10", 1)]
+    fn from_str_test_err(#[case] input: &str, #[case] column: usize) {
+        let error = input.parse::<SensorBeaconPair>().unwrap_err();
+
+        let expected = format!("Could not parse sensor and beacon at column {column}.");
+        assert_eq!(expected, error.to_string());
+    }
+
+    #[test]
+    fn manhattan_between_test() {
+        let pair = SensorBeaconPair {
+            sensor: Point::new(8, 7),
+            beacon: Point::new(2, 10),
+        };
+
+        assert_eq!(9, pair.manhattan_between());
+    }
 }
@@ -0,0 +1,5 @@
+mod error;
+mod parser;
+
+pub use error::SensorBeaconPairParseError;
+pub use parser::parse_sensor_beacon_pair;
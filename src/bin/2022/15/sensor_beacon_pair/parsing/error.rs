@@ -0,0 +1,20 @@
+use nom::error::VerboseError;
+use snafu::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Snafu)]
+#[snafu(display("Could not parse sensor and beacon at column {column}."))]
+pub struct SensorBeaconPairParseError {
+    column: usize,
+}
+
+impl SensorBeaconPairParseError {
+    pub fn with_parse_context(input: &str, error: VerboseError<&str>) -> Self {
+        let remaining = error
+            .errors
+            .first()
+            .map_or(input, |(remaining, _)| remaining);
+        Self {
+            column: input.len() - remaining.len() + 1,
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::{num::ParseIntError, str::FromStr};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, digit1},
+    combinator::{map, map_res, opt, recognize},
+    error::{FromExternalError, ParseError},
+    sequence::{preceded, separated_pair, tuple},
+    IResult,
+};
+
+use util::geometry::Point;
+
+use crate::y2022::d15::sensor_beacon_pair::SensorBeaconPair;
+
+pub fn parse_sensor_beacon_pair<'a, E>(input: &'a str) -> IResult<&'a str, SensorBeaconPair, E>
+where
+    E: ParseError<&'a str> + FromExternalError<&'a str, ParseIntError>,
+{
+    map(
+        tuple((
+            preceded(tag("Sensor at "), parse_coordinate),
+            preceded(tag(": closest beacon is at "), parse_coordinate),
+        )),
+        |(sensor, beacon)| SensorBeaconPair { sensor, beacon },
+    )(input)
+}
+
+fn parse_coordinate<'a, E>(input: &'a str) -> IResult<&'a str, Point<i32>, E>
+where
+    E: ParseError<&'a str> + FromExternalError<&'a str, ParseIntError>,
+{
+    map(
+        separated_pair(
+            preceded(tag("x="), parse_number),
+            tag(", "),
+            preceded(tag("y="), parse_number),
+        ),
+        |(x, y)| Point::new(x, y),
+    )(input)
+}
+
+fn parse_number<'a, E>(input: &'a str) -> IResult<&'a str, i32, E>
+where
+    E: ParseError<&'a str> + FromExternalError<&'a str, ParseIntError>,
+{
+    map_res(
+        recognize(tuple((opt(char('-')), digit1))),
+        FromStr::from_str,
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        error::{convert_error, VerboseError},
+        Finish, Parser,
+    };
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn unwrap_verbose<'a, O>(
+        mut parser: impl Parser<&'a str, O, VerboseError<&'a str>>,
+        input: &'a str,
+    ) -> (&'a str, O) {
+        parser
+            .parse(input)
+            .finish()
+            .unwrap_or_else(|error| panic!("{}", convert_error(input, error)))
+    }
+
+    #[test]
+    fn parse_sensor_beacon_pair_test() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
+        let expected = SensorBeaconPair {
+            sensor: Point::new(2, 18),
+            beacon: Point::new(-2, 15),
+        };
+
+        let (remaining_input, actual) = unwrap_verbose(parse_sensor_beacon_pair, input);
+
+        assert_eq!(expected, actual);
+        assert_eq!(remaining_input, "");
+    }
+
+    #[rstest]
+    #[case(Point::new(0, 0), "x=0, y=0")]
+    #[case(Point::new(3523437, 2746095), "x=3523437, y=2746095")]
+    #[case(Point::new(-5, -10), "x=-5, y=-10")]
+    fn parse_coordinate_test(#[case] expected: Point<i32>, #[case] input: &str) {
+        let (remaining_input, actual) = unwrap_verbose(parse_coordinate, input);
+
+        assert_eq!(expected, actual);
+        assert_eq!(remaining_input, "");
+    }
+
+    #[rstest]
+    #[case("x=5 y=3")]
+    #[case("y=5, x=3")]
+    #[case("x=a, y=3")]
+    #[case("x=99999999999, y=3")]
+    #[should_panic]
+    fn parse_coordinate_test_invalid(#[case] input: &str) {
+        parse_coordinate::<VerboseError<&str>>(input).unwrap();
+    }
+}
@@ -0,0 +1,32 @@
+#[path = "01/mod.rs"]
+mod d01;
+#[path = "02/mod.rs"]
+mod d02;
+#[path = "03/mod.rs"]
+mod d03;
+#[path = "04/mod.rs"]
+mod d04;
+#[path = "05/mod.rs"]
+mod d05;
+#[path = "06/mod.rs"]
+mod d06;
+#[path = "09/mod.rs"]
+mod d09;
+#[path = "12/mod.rs"]
+mod d12;
+#[path = "15/mod.rs"]
+mod d15;
+
+use util::aoc::Registry;
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<d01::CalorieCounting>();
+    registry.register::<d02::RockPaperScissors>();
+    registry.register::<d03::RucksackReorganization>();
+    registry.register::<d04::CampCleanup>();
+    registry.register::<d05::SupplyStacks>();
+    registry.register::<d06::TuningTrouble>();
+    registry.register::<d09::RopeBridge>();
+    registry.register::<d12::HillClimbingAlgorithm>();
+    registry.register::<d15::BeaconExclusionZone>();
+}
@@ -0,0 +1,185 @@
+#[path = "2015/mod.rs"]
+mod y2015;
+#[path = "2016/mod.rs"]
+mod y2016;
+#[path = "2022/mod.rs"]
+mod y2022;
+
+use std::{
+    any::Any,
+    env,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    process::ExitCode,
+    str::FromStr,
+};
+
+use util::aoc::{
+    Benchmark, BenchmarkTable, InputResolver, Inputs, Puzzle, Registry, INPUT_OVERRIDE_VARIABLE,
+};
+
+const USAGE: &str = "Usage:
+    aoc run <year> <day> [<input>]
+    aoc bench <iterations> [<year> [<day> [<input>]]]
+    aoc list
+
+The actual input defaults to `res/<year>/<day>/input.txt` (or `actual.txt`).
+It can be overridden by passing <input> or by setting `AOC_INPUT`; `bench` only accepts an
+override when a single year and day are selected. Answers and parameters of an overridden input
+are read from a sidecar next to it, e.g. `path/to/input.toml`.";
+
+enum Command {
+    Run {
+        year: u16,
+        day: u16,
+        input: Option<PathBuf>,
+    },
+    Bench {
+        iterations: NonZeroUsize,
+        year: Option<u16>,
+        day: Option<u16>,
+        input: Option<PathBuf>,
+    },
+    List,
+}
+
+impl Command {
+    fn parse(arguments: &[String]) -> Result<Self, String> {
+        let arguments = arguments.iter().map(String::as_str).collect::<Vec<_>>();
+        match arguments.as_slice() {
+            ["run", year, day, input @ ..] if input.len() <= 1 => Ok(Self::Run {
+                year: parse_number(year)?,
+                day: parse_number(day)?,
+                input: input.first().map(PathBuf::from),
+            }),
+            ["bench", iterations, filter @ ..] if filter.len() <= 3 => Ok(Self::Bench {
+                iterations: parse_number(iterations)?,
+                year: filter.first().map(|year| parse_number(year)).transpose()?,
+                day: filter.get(1).map(|day| parse_number(day)).transpose()?,
+                input: filter.get(2).map(PathBuf::from),
+            }),
+            ["list"] => Ok(Self::List),
+            _ => Err(USAGE.to_string()),
+        }
+    }
+}
+
+fn parse_number<T: FromStr>(argument: &str) -> Result<T, String> {
+    argument
+        .parse()
+        .map_err(|_| format!("Argument `{argument}` is not a valid number.\n{USAGE}"))
+}
+
+/// Resolves the actual input to `input` if it is given, otherwise to the one in `AOC_INPUT` or
+/// the default one.
+fn resolver(input: Option<PathBuf>) -> InputResolver {
+    match input {
+        Some(input) => InputResolver::default().with_actual(input),
+        None => InputResolver::from_env(),
+    }
+}
+
+/// Like [`resolver`] for a single day, but one file cannot be the input of several days, so
+/// `AOC_INPUT` is ignored when benchmarking more than one.
+fn bench_resolver(year: Option<u16>, day: Option<u16>, input: Option<PathBuf>) -> InputResolver {
+    if year.is_some() && day.is_some() {
+        return resolver(input);
+    }
+    if env::var_os(INPUT_OVERRIDE_VARIABLE).is_some_and(|path| !path.is_empty()) {
+        eprintln!(
+            "Warning: `{INPUT_OVERRIDE_VARIABLE}` is ignored unless a single year and day are \
+             benchmarked."
+        );
+    }
+    InputResolver::default()
+}
+
+/// Benchmarks a single puzzle, turning a panic into an error so that the others still run.
+fn bench(puzzle: &Puzzle, inputs: &Inputs, iterations: NonZeroUsize) -> Result<Benchmark, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| puzzle.bench(inputs, iterations)))
+        .map_err(|payload| format!("It panicked: {message}", message = panic_message(&*payload)))?
+        .map_err(|err| err.to_string())
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown cause")
+}
+
+/// Resolves the inputs and warns about parameters that do not apply to an overridden input.
+fn resolve(resolver: &InputResolver, year: u16, day: u16) -> Result<Inputs, String> {
+    let inputs = resolver
+        .resolve(year, day)
+        .map_err(|err| format!("{err}\n{source}", source = err.source))?;
+    if !inputs.ignored_parameters().is_empty() {
+        eprintln!(
+            "Warning: the parameters `{parameters}` of `answers.toml` do not apply to the overridden \
+             input; set them in a sidecar next to it.",
+            parameters = inputs.ignored_parameters().join("`, `")
+        );
+    }
+    Ok(inputs)
+}
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    y2015::register(&mut registry);
+    y2016::register(&mut registry);
+    y2022::register(&mut registry);
+    registry
+}
+
+fn main() -> ExitCode {
+    let arguments = env::args().skip(1).collect::<Vec<_>>();
+    match execute(&arguments) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn execute(arguments: &[String]) -> Result<(), String> {
+    let registry = registry();
+
+    match Command::parse(arguments)? {
+        Command::Run { year, day, input } => {
+            let puzzle = registry.find(year, day).map_err(|err| err.to_string())?;
+            let inputs = resolve(&resolver(input), year, day)?;
+            puzzle.run(&inputs).map_err(|err| err.to_string())?;
+        }
+        Command::Bench {
+            iterations,
+            year,
+            day,
+            input,
+        } => {
+            let resolver = bench_resolver(year, day, input);
+            let mut rows = Vec::new();
+            for puzzle in registry.puzzles().filter(|puzzle| {
+                year.is_none_or(|year| year == puzzle.year())
+                    && day.is_none_or(|day| day == puzzle.day())
+            }) {
+                let benchmark = resolve(&resolver, puzzle.year(), puzzle.day())
+                    .and_then(|inputs| bench(puzzle, &inputs, iterations));
+                match benchmark {
+                    Ok(benchmark) => rows.push((puzzle.to_string(), benchmark)),
+                    Err(error) => eprintln!("Skipping puzzle `{puzzle}`: {error}"),
+                }
+            }
+            print!("{table}", table = BenchmarkTable::new(&rows));
+        }
+        Command::List => {
+            for puzzle in registry.puzzles() {
+                println!("{puzzle}");
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,309 @@
+use std::{
+    collections::BTreeSet,
+    env, fs,
+    io::{self, BufRead, Write},
+    process::ExitCode,
+    str::FromStr,
+};
+
+use util::assembunny::{
+    lint, Assembunny, ExecutionOutcome, HaltReason, MissingRegisterError, RegisterId, Registers,
+    RuntimeEnvironment, TraceEntry,
+};
+
+const USAGE: &str = "Usage:
+    assembunny <program>
+
+Loads the Assembunny program, prints warnings about its control flow and starts an interactive
+debugger. Type `help` for a list of commands.";
+
+const HELP: &str = "Commands:
+    step [<count>]      Execute the next <count> instructions (default 1) and print them.
+    continue            Run until a breakpoint or watchpoint is hit or the program stops.
+    break <ip>          Toggle a breakpoint before the instruction at <ip>.
+    watch <register>    Toggle a watchpoint that stops when <register> changes.
+    trace on|off        Record every executed instruction.
+    history [<count>]   Print the last <count> recorded instructions (default 10).
+    registers           Print the instruction pointer and the registers.
+    list                Print the program and mark the next instruction.
+    listing             Print the program with labelled jump targets and marked loops.
+    hot [<count>]       Print the <count> most executed instructions (default 5).
+    help                Print this help.
+    quit                Leave the debugger.";
+
+enum Command {
+    Step(usize),
+    Continue,
+    Break(isize),
+    Watch(RegisterId),
+    Trace(bool),
+    History(usize),
+    Registers,
+    List,
+    Listing,
+    Hot(usize),
+    Help,
+    Quit,
+}
+
+impl Command {
+    fn parse(line: &str) -> Result<Self, String> {
+        let arguments = line.split_whitespace().collect::<Vec<_>>();
+        match arguments.as_slice() {
+            ["step" | "s"] => Ok(Self::Step(1)),
+            ["step" | "s", count] => Ok(Self::Step(parse_number(count)?)),
+            ["continue" | "c"] => Ok(Self::Continue),
+            ["break" | "b", ip] => Ok(Self::Break(parse_number(ip)?)),
+            ["watch" | "w", register] => Ok(Self::Watch(parse_register_id(register)?)),
+            ["trace", "on"] => Ok(Self::Trace(true)),
+            ["trace", "off"] => Ok(Self::Trace(false)),
+            ["history"] => Ok(Self::History(10)),
+            ["history", count] => Ok(Self::History(parse_number(count)?)),
+            ["registers" | "r"] => Ok(Self::Registers),
+            ["list" | "l"] => Ok(Self::List),
+            ["listing"] => Ok(Self::Listing),
+            ["hot"] => Ok(Self::Hot(5)),
+            ["hot", count] => Ok(Self::Hot(parse_number(count)?)),
+            ["help" | "h"] => Ok(Self::Help),
+            ["quit" | "q"] => Ok(Self::Quit),
+            _ => Err(format!("Unknown command `{line}`.\n{HELP}")),
+        }
+    }
+}
+
+fn parse_number<T: FromStr>(argument: &str) -> Result<T, String> {
+    argument
+        .parse()
+        .map_err(|_| format!("Argument `{argument}` is not a valid number."))
+}
+
+fn parse_register_id(argument: &str) -> Result<RegisterId, String> {
+    let mut chars = argument.chars();
+    match (chars.next().and_then(RegisterId::new), chars.next()) {
+        (Some(register_id), None) => Ok(register_id),
+        _ => Err(format!("Argument `{argument}` is not a register.")),
+    }
+}
+
+struct Debugger {
+    runtime_environment: RuntimeEnvironment,
+    breakpoints: BTreeSet<isize>,
+    watchpoints: Vec<RegisterId>,
+    execution_counts: Vec<usize>,
+}
+
+impl Debugger {
+    fn new(assembunny: Assembunny) -> Result<Self, MissingRegisterError> {
+        Ok(Self {
+            execution_counts: vec![0; assembunny.len()],
+            runtime_environment: RuntimeEnvironment::load_assembunny(assembunny)?,
+            breakpoints: BTreeSet::new(),
+            watchpoints: Vec::new(),
+        })
+    }
+
+    fn execute(&mut self, command: Command) {
+        match command {
+            Command::Step(count) => {
+                for _ in 0..count {
+                    match self.runtime_environment.step() {
+                        Ok(entry) => {
+                            self.count(&entry);
+                            println!("{}", format_entry(&entry));
+                        }
+                        Err(reason) => {
+                            println!("{}.", describe(reason));
+                            break;
+                        }
+                    }
+                }
+            }
+            Command::Continue => {
+                let Self {
+                    runtime_environment,
+                    breakpoints,
+                    watchpoints,
+                    execution_counts,
+                } = self;
+                let outcome = runtime_environment.run_until(|runtime_environment, entry| {
+                    execution_counts[entry.ip as usize] += 1;
+                    breakpoints.contains(&runtime_environment.ip())
+                        || watchpoints.iter().any(|&register_id| {
+                            entry.before.register_value(register_id)
+                                != entry.after.register_value(register_id)
+                        })
+                });
+                println!("{}", format_outcome(&outcome));
+                self.print_registers();
+            }
+            Command::Break(ip) => {
+                if self.breakpoints.remove(&ip) {
+                    println!("Removed breakpoint at {ip}.");
+                } else {
+                    self.breakpoints.insert(ip);
+                    println!("Added breakpoint at {ip}.");
+                }
+            }
+            Command::Watch(register_id)
+                if !self.runtime_environment.registers().contains(register_id) =>
+            {
+                println!("There is no register {register_id}.");
+            }
+            Command::Watch(register_id) => {
+                match self.watchpoints.iter().position(|&id| id == register_id) {
+                    Some(index) => {
+                        self.watchpoints.remove(index);
+                        println!("Removed watchpoint on {register_id}.");
+                    }
+                    None => {
+                        self.watchpoints.push(register_id);
+                        println!("Added watchpoint on {register_id}.");
+                    }
+                }
+            }
+            Command::Trace(enabled) => {
+                self.runtime_environment.set_tracing(enabled);
+                println!("Tracing is {}.", if enabled { "on" } else { "off" });
+            }
+            Command::History(count) => {
+                let trace = self.runtime_environment.trace();
+                for entry in &trace[trace.len().saturating_sub(count)..] {
+                    println!("{}", format_entry(entry));
+                }
+            }
+            Command::Registers => self.print_registers(),
+            Command::List => {
+                for (ip, instruction) in self.runtime_environment.assembunny().iter().enumerate() {
+                    let ip = ip as isize;
+                    let marker = if ip == self.runtime_environment.ip() {
+                        ">"
+                    } else {
+                        " "
+                    };
+                    let breakpoint = if self.breakpoints.contains(&ip) {
+                        "*"
+                    } else {
+                        " "
+                    };
+                    println!("{marker}{breakpoint}{ip:>4}  {instruction}");
+                }
+            }
+            Command::Listing => print!("{}", self.runtime_environment.assembunny().listing()),
+            Command::Hot(count) => {
+                let mut hot = self
+                    .execution_counts
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, executions)| **executions > 0)
+                    .collect::<Vec<_>>();
+                hot.sort_by(|(ip_a, a), (ip_b, b)| b.cmp(a).then(ip_a.cmp(ip_b)));
+                let assembunny = self.runtime_environment.assembunny();
+                for (ip, executions) in hot.into_iter().take(count) {
+                    println!("{ip:>4}  {executions:>12}  {}", assembunny[ip]);
+                }
+            }
+            Command::Help => println!("{HELP}"),
+            Command::Quit => {}
+        }
+    }
+
+    fn count(&mut self, entry: &TraceEntry) {
+        self.execution_counts[entry.ip as usize] += 1;
+    }
+
+    fn print_registers(&self) {
+        println!(
+            "ip={} {}",
+            self.runtime_environment.ip(),
+            format_registers(&self.runtime_environment.registers())
+        );
+    }
+}
+
+fn format_registers(registers: &Registers) -> String {
+    registers
+        .register_ids()
+        .map(|register_id| format!("{register_id}={}", registers[register_id]))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn format_entry(entry: &TraceEntry) -> String {
+    format!(
+        "{:>4}  {}  [{}] -> [{}]",
+        entry.ip,
+        entry.instruction,
+        format_registers(&entry.before),
+        format_registers(&entry.after)
+    )
+}
+
+fn describe(reason: HaltReason) -> &'static str {
+    match reason {
+        HaltReason::Halted => "The program has halted",
+        HaltReason::StepLimitReached => "The step limit was reached",
+        HaltReason::LoopDetected => "The program is stuck in a loop",
+        HaltReason::BreakpointReached => "Stopped at a breakpoint or watchpoint",
+        HaltReason::Overflow => "The next instruction overflows",
+    }
+}
+
+fn format_outcome(outcome: &ExecutionOutcome) -> String {
+    format!(
+        "{} after {} steps.",
+        describe(outcome.reason),
+        outcome.steps
+    )
+}
+
+fn main() -> ExitCode {
+    let arguments = env::args().skip(1).collect::<Vec<_>>();
+    match execute(&arguments) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn execute(arguments: &[String]) -> Result<(), String> {
+    let [path] = arguments else {
+        return Err(USAGE.to_string());
+    };
+    let program = fs::read_to_string(path)
+        .map_err(|err| format!("File `{path}` could not be read.\n{err}"))?;
+    let assembunny = program
+        .parse::<Assembunny>()
+        .map_err(|err| err.to_string())?;
+    for warning in lint(&assembunny) {
+        eprintln!("warning: {warning}");
+    }
+    let mut debugger = Debugger::new(assembunny).map_err(|err| err.to_string())?;
+
+    let stdin = io::stdin();
+    loop {
+        print!("(bunny) ");
+        io::stdout().flush().map_err(|err| err.to_string())?;
+
+        let mut line = String::new();
+        if stdin
+            .lock()
+            .read_line(&mut line)
+            .map_err(|err| err.to_string())?
+            == 0
+        {
+            return Ok(());
+        }
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        match Command::parse(line) {
+            Ok(Command::Quit) => return Ok(()),
+            Ok(command) => debugger.execute(command),
+            Err(error) => eprintln!("{error}"),
+        }
+    }
+}
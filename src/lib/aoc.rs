@@ -1,5 +1,11 @@
+mod bench;
 mod inputs;
 mod part;
+mod registry;
+mod solution;
 
+pub use bench::*;
 pub use inputs::*;
 pub use part::*;
+pub use registry::*;
+pub use solution::*;
@@ -0,0 +1,167 @@
+use std::{
+    fmt::Display,
+    num::NonZeroUsize,
+    time::{Duration, Instant},
+};
+
+use crate::aoc::{solution::parse, Inputs, Part, RunError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Statistics {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Statistics {
+    /// Returns `None` if there are no samples. For an even number of samples the upper one of
+    /// the two middle samples is used as median.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort_unstable();
+        Some(Self {
+            min: *samples.first()?,
+            median: samples[samples.len() / 2],
+            max: *samples.last()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Benchmark {
+    pub parse: Statistics,
+    pub part_one: Statistics,
+    pub part_two: Statistics,
+}
+
+impl Benchmark {
+    pub fn stages(&self) -> [(&'static str, Statistics); 3] {
+        [
+            ("parse", self.parse),
+            ("part 1", self.part_one),
+            ("part 2", self.part_two),
+        ]
+    }
+}
+
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+pub fn bench<S: Solution>(
+    inputs: &Inputs,
+    iterations: NonZeroUsize,
+) -> Result<Benchmark, RunError> {
+    let parts = [Part::<S>::one(), Part::<S>::two()];
+    let mut samples = [(); 3].map(|_| Vec::with_capacity(iterations.get()));
+
+    for _ in 0..iterations.get() {
+        let (input, elapsed) = time(|| parse::<S>(inputs.actual()));
+        let input = input?;
+        samples[0].push(elapsed);
+
+        for (part, samples) in parts.iter().zip(&mut samples[1..]) {
+            let (answer, elapsed) = time(|| part.solve(&input, inputs.parameters()));
+            answer?;
+            samples.push(elapsed);
+        }
+    }
+
+    let [parse, part_one, part_two] =
+        samples.map(|samples| Statistics::from_samples(samples).expect("iterations are non-zero"));
+    Ok(Benchmark {
+        parse,
+        part_one,
+        part_two,
+    })
+}
+
+pub struct BenchmarkTable<'a> {
+    rows: &'a [(String, Benchmark)],
+}
+
+impl<'a> BenchmarkTable<'a> {
+    pub fn new(rows: &'a [(String, Benchmark)]) -> Self {
+        Self { rows }
+    }
+}
+
+impl Display for BenchmarkTable<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:<8} {:<6} {:>12} {:>12} {:>12}",
+            "Puzzle", "Stage", "Min", "Median", "Max"
+        )?;
+        for (puzzle, benchmark) in self.rows {
+            for (stage, statistics) in benchmark.stages() {
+                writeln!(
+                    f,
+                    "{:<8} {:<6} {:>12} {:>12} {:>12}",
+                    puzzle,
+                    stage,
+                    format!("{:.2?}", statistics.min),
+                    format!("{:.2?}", statistics.median),
+                    format!("{:.2?}", statistics.max),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod bench_tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    #[rstest]
+    #[case(&[5], (5, 5, 5))]
+    #[case(&[9, 1, 5], (1, 5, 9))]
+    #[case(&[4, 2, 8, 6], (2, 6, 8))]
+    fn from_samples_test_some(#[case] samples: &[u64], #[case] expected: (u64, u64, u64)) {
+        let expected = Statistics {
+            min: Duration::from_millis(expected.0),
+            median: Duration::from_millis(expected.1),
+            max: Duration::from_millis(expected.2),
+        };
+
+        assert_eq!(Some(expected), Statistics::from_samples(millis(samples)));
+    }
+
+    #[test]
+    fn from_samples_test_none() {
+        assert_eq!(None, Statistics::from_samples(Vec::new()));
+    }
+
+    #[test]
+    fn table_test() {
+        let statistics = Statistics::from_samples(millis(&[1, 2, 3])).unwrap();
+        let rows = [(
+            "2015/06".to_string(),
+            Benchmark {
+                parse: statistics,
+                part_one: statistics,
+                part_two: statistics,
+            },
+        )];
+
+        let table = BenchmarkTable::new(&rows).to_string();
+
+        let expected = [
+            "Puzzle   Stage           Min       Median          Max",
+            "2015/06  parse        1.00ms       2.00ms       3.00ms",
+            "2015/06  part 1       1.00ms       2.00ms       3.00ms",
+            "2015/06  part 2       1.00ms       2.00ms       3.00ms",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(expected, table);
+    }
+}
@@ -1,7 +1,19 @@
-use std::{fs, io};
+use std::{
+    collections::BTreeMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
-const EXAMPLE_FILENAME: &str = "example.txt";
-const ACTUAL_FILENAME: &str = "actual.txt";
+pub const INPUT_OVERRIDE_VARIABLE: &str = "AOC_INPUT";
+
+const RESOURCE_ROOT: &str = "./res";
+const EXAMPLE_PREFIX: &str = "example";
+const EXAMPLE_EXTENSION: &str = "txt";
+const CONFIG_EXTENSION: &str = "toml";
+const ACTUAL_FILENAMES: [&str; 2] = ["input.txt", "actual.txt"];
+const ANSWERS_FILENAME: &str = "answers.toml";
+const PARAMETERS_KEY: &str = "parameters";
 
 #[derive(Debug, thiserror::Error)]
 #[error("File `{file}` could not be read.")]
@@ -11,31 +23,561 @@ pub struct ReadInputsError {
     pub file: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Parameter `{key}` has the invalid value `{value}`.")]
+pub struct InvalidParameterError {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Expected {
+    part_one: Option<String>,
+    part_two: Option<String>,
+}
+
+impl Expected {
+    pub fn new(part_one: Option<&str>, part_two: Option<&str>) -> Self {
+        Self {
+            part_one: part_one.map(str::to_string),
+            part_two: part_two.map(str::to_string),
+        }
+    }
+
+    pub fn part(&self, number: u8) -> Option<&str> {
+        match number {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Named values such as a row number or a grid size that differ between the examples and the
+/// actual input of a puzzle.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Parameters(BTreeMap<String, String>);
+
+impl Parameters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, key: &str, value: impl ToString) -> Self {
+        self.0.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Returns `Ok(None)` if the parameter is not set.
+    pub fn get<T: FromStr>(&self, key: &str) -> Result<Option<T>, InvalidParameterError> {
+        self.0
+            .get(key)
+            .map(|value| {
+                value.parse().map_err(|_| InvalidParameterError {
+                    key: key.to_string(),
+                    value: value.clone(),
+                })
+            })
+            .transpose()
+    }
+
+    /// Returns `default` if the parameter is not set.
+    pub fn get_or<T: FromStr>(&self, key: &str, default: T) -> Result<T, InvalidParameterError> {
+        Ok(self.get(key)?.unwrap_or(default))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.0.contains_key(key)
+    }
+}
+
+/// The contents of `answers.toml` and of the `example*.toml` sidecars: the optional answers
+/// `part_one` and `part_two` and an optional `[parameters]` table.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InputConfig {
+    expected: Expected,
+    parameters: Parameters,
+}
+
+impl InputConfig {
+    pub fn new(expected: Expected, parameters: Parameters) -> Self {
+        Self {
+            expected,
+            parameters,
+        }
+    }
+
+    /// Answers and parameters may be given as strings or as integers.
+    pub fn from_toml(content: &str) -> Result<Self, toml::de::Error> {
+        let table = content.parse::<toml::Table>()?;
+        let answer = |key: &str| table.get(key).map(value_to_string);
+        let parameters = match table.get(PARAMETERS_KEY) {
+            Some(toml::Value::Table(parameters)) => parameters
+                .iter()
+                .map(|(key, value)| (key.clone(), value_to_string(value)))
+                .collect(),
+            _ => BTreeMap::new(),
+        };
+        Ok(Self {
+            expected: Expected {
+                part_one: answer("part_one"),
+                part_two: answer("part_two"),
+            },
+            parameters: Parameters(parameters),
+        })
+    }
+
+    pub fn expected(&self) -> &Expected {
+        &self.expected
+    }
+
+    pub fn parameters(&self) -> &Parameters {
+        &self.parameters
+    }
+}
+
+fn value_to_string(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    name: String,
+    content: String,
+    config: InputConfig,
+}
+
+impl Example {
+    pub fn new(name: impl Into<String>, content: impl Into<String>, config: InputConfig) -> Self {
+        Self {
+            name: name.into(),
+            content: content.into(),
+            config,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn expected(&self) -> &Expected {
+        self.config.expected()
+    }
+
+    pub fn parameters(&self) -> &Parameters {
+        self.config.parameters()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Inputs {
-    example: String,
+    examples: Vec<Example>,
     actual: String,
+    config: InputConfig,
+    ignored_parameters: Vec<String>,
 }
 
 impl Inputs {
     pub fn read(year: u16, day: u16) -> Result<Self, ReadInputsError> {
-        let example = read_file(year, day, EXAMPLE_FILENAME)?;
-        let input = read_file(year, day, ACTUAL_FILENAME)?;
-        Ok(Self {
-            example,
-            actual: input,
-        })
+        InputResolver::from_env().resolve(year, day)
     }
 
-    pub fn example(&self) -> &str {
-        &self.example
+    pub fn examples(&self) -> &[Example] {
+        &self.examples
     }
 
     pub fn actual(&self) -> &str {
         &self.actual
     }
+
+    pub fn answers(&self) -> &Expected {
+        self.config.expected()
+    }
+
+    pub fn parameters(&self) -> &Parameters {
+        self.config.parameters()
+    }
+
+    /// The parameters in `answers.toml` that do not apply because the actual input was overridden
+    /// and the sidecar of the override does not set them either.
+    pub fn ignored_parameters(&self) -> &[String] {
+        &self.ignored_parameters
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InputResolver {
+    root: PathBuf,
+    actual_override: Option<PathBuf>,
+}
+
+impl Default for InputResolver {
+    fn default() -> Self {
+        Self::new(RESOURCE_ROOT)
+    }
+}
+
+impl InputResolver {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            actual_override: None,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let resolver = Self::default();
+        match env::var_os(INPUT_OVERRIDE_VARIABLE) {
+            Some(path) if !path.is_empty() => resolver.with_actual(path),
+            _ => resolver,
+        }
+    }
+
+    pub fn with_actual(mut self, path: impl Into<PathBuf>) -> Self {
+        self.actual_override = Some(path.into());
+        self
+    }
+
+    /// An overridden actual input takes its answers and parameters from a sidecar next to it,
+    /// e.g. `path/to/input.toml` for `path/to/input.txt`, instead of from `answers.toml`.
+    pub fn resolve(&self, year: u16, day: u16) -> Result<Inputs, ReadInputsError> {
+        let directory = self.root.join(format!("{year:0>4}/{day:0>2}"));
+        let examples = read_examples(&directory)?;
+        let answers = read_config(&directory.join(ANSWERS_FILENAME))?;
+        let (actual, config) = match &self.actual_override {
+            Some(path) => (
+                read(path)?,
+                read_config(&path.with_extension(CONFIG_EXTENSION))?,
+            ),
+            None => (read_actual(&directory)?, answers.clone()),
+        };
+        let ignored_parameters = answers
+            .parameters()
+            .keys()
+            .filter(|key| !config.parameters().contains(key))
+            .map(str::to_string)
+            .collect();
+        Ok(Inputs {
+            examples,
+            actual,
+            config,
+            ignored_parameters,
+        })
+    }
 }
 
-fn read_file(year: u16, day: u16, filename: &str) -> Result<String, ReadInputsError> {
-    let file = format!("./res/{year:0>4}/{day:0>2}/{filename}");
-    fs::read_to_string(&file).map_err(|source| ReadInputsError { source, file })
+fn read_examples(directory: &Path) -> Result<Vec<Example>, ReadInputsError> {
+    let entries = match fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(source) if source.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(source) => {
+            return Err(ReadInputsError {
+                source,
+                file: directory.display().to_string(),
+            })
+        }
+    };
+
+    let mut names = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == EXAMPLE_EXTENSION)
+        })
+        .filter_map(|path| path.file_stem()?.to_str().map(str::to_string))
+        .filter(|name| name.starts_with(EXAMPLE_PREFIX))
+        .collect::<Vec<_>>();
+    names.sort_by(|a, b| (a.len(), a).cmp(&(b.len(), b)));
+
+    names
+        .into_iter()
+        .map(|name| {
+            let path = directory.join(&name);
+            let content = read(&path.with_extension(EXAMPLE_EXTENSION))?;
+            let config = read_config(&path.with_extension(CONFIG_EXTENSION))?;
+            Ok(Example::new(name, content, config))
+        })
+        .collect()
+}
+
+fn read_actual(directory: &Path) -> Result<String, ReadInputsError> {
+    for filename in ACTUAL_FILENAMES {
+        if let Some(actual) = read_optional(&directory.join(filename))? {
+            return Ok(actual);
+        }
+    }
+    read(&directory.join(ACTUAL_FILENAMES[0]))
+}
+
+fn read_config(path: &Path) -> Result<InputConfig, ReadInputsError> {
+    let Some(config) = read_optional(path)? else {
+        return Ok(InputConfig::default());
+    };
+    InputConfig::from_toml(&config).map_err(|error| ReadInputsError {
+        source: io::Error::new(io::ErrorKind::InvalidData, error),
+        file: path.display().to_string(),
+    })
+}
+
+fn read_optional(path: &Path) -> Result<Option<String>, ReadInputsError> {
+    match read(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(error) if error.source.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+fn read(path: &Path) -> Result<String, ReadInputsError> {
+    fs::read_to_string(path).map_err(|source| ReadInputsError {
+        source,
+        file: path.display().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod inputs_tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    struct TempRoot(PathBuf);
+
+    impl TempRoot {
+        fn new(name: &str) -> Self {
+            let root = env::temp_dir().join(format!("aoc-inputs-{}-{name}", std::process::id()));
+            let _ = fs::remove_dir_all(&root);
+            fs::create_dir_all(root.join("2016/02")).unwrap();
+            Self(root)
+        }
+
+        fn write(&self, filename: &str, content: &str) -> PathBuf {
+            let path = self.0.join("2016/02").join(filename);
+            fs::write(&path, content).unwrap();
+            path
+        }
+    }
+
+    impl Drop for TempRoot {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn resolve_test_example_optional() {
+        let root = TempRoot::new("example-optional");
+        root.write("input.txt", "actual");
+
+        let inputs = InputResolver::new(&root.0).resolve(2016, 2).unwrap();
+
+        assert!(inputs.examples().is_empty());
+        assert_eq!("actual", inputs.actual());
+    }
+
+    #[test]
+    fn resolve_test_actual_filename() {
+        let root = TempRoot::new("actual-filename");
+        root.write("example.txt", "example");
+        root.write("actual.txt", "actual");
+
+        let inputs = InputResolver::new(&root.0).resolve(2016, 2).unwrap();
+
+        assert_eq!("example", inputs.examples()[0].content());
+        assert_eq!("actual", inputs.actual());
+    }
+
+    #[test]
+    fn resolve_test_override() {
+        let root = TempRoot::new("override");
+        root.write("input.txt", "actual");
+        let path = root.write("other.txt", "other");
+
+        let inputs = InputResolver::new(&root.0)
+            .with_actual(path)
+            .resolve(2016, 2)
+            .unwrap();
+
+        assert_eq!("other", inputs.actual());
+    }
+
+    #[test]
+    fn resolve_test_err() {
+        let root = TempRoot::new("missing");
+
+        let error = InputResolver::new(&root.0).resolve(2016, 2).unwrap_err();
+
+        assert_eq!(io::ErrorKind::NotFound, error.source.kind());
+        assert!(error.file.ends_with("input.txt"));
+    }
+
+    #[test]
+    fn resolve_test_examples() {
+        let root = TempRoot::new("examples");
+        root.write("input.txt", "actual");
+        root.write("example10.txt", "ten");
+        root.write("example2.txt", "two");
+        root.write("example2.toml", "part_one = 4\n");
+        root.write("example.txt", "plain");
+        root.write("example.toml", "part_one = 1\npart_two = \"2\"\n");
+
+        let inputs = InputResolver::new(&root.0).resolve(2016, 2).unwrap();
+
+        let names = inputs
+            .examples()
+            .iter()
+            .map(Example::name)
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["example", "example2", "example10"], names);
+        let expected = inputs
+            .examples()
+            .iter()
+            .map(|example| (example.expected().part(1), example.expected().part(2)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![(Some("1"), Some("2")), (Some("4"), None), (None, None)],
+            expected
+        );
+    }
+
+    #[test]
+    fn resolve_test_answers() {
+        let root = TempRoot::new("answers");
+        root.write("input.txt", "actual");
+        root.write("answers.toml", "part_one = 12578\npart_two = \"516DD\"\n");
+
+        let inputs = InputResolver::new(&root.0).resolve(2016, 2).unwrap();
+
+        assert_eq!(Some("12578"), inputs.answers().part(1));
+        assert_eq!(Some("516DD"), inputs.answers().part(2));
+    }
+
+    #[test]
+    fn resolve_test_answers_ignored_on_override() {
+        let root = TempRoot::new("answers-override");
+        root.write("answers.toml", "part_one = 1\n");
+        let path = root.write("other.txt", "other");
+
+        let inputs = InputResolver::new(&root.0)
+            .with_actual(path)
+            .resolve(2016, 2)
+            .unwrap();
+
+        assert_eq!(&Expected::default(), inputs.answers());
+        assert_eq!(&Parameters::default(), inputs.parameters());
+    }
+
+    #[test]
+    fn resolve_test_override_sidecar() {
+        let root = TempRoot::new("override-sidecar");
+        root.write("input.txt", "actual");
+        root.write(
+            "answers.toml",
+            "[parameters]\nrow = 2_000_000\nsize = 1000\n",
+        );
+        let path = root.write("other.txt", "other");
+        root.write("other.toml", "part_one = 26\n\n[parameters]\nrow = 10\n");
+
+        let inputs = InputResolver::new(&root.0)
+            .with_actual(path)
+            .resolve(2016, 2)
+            .unwrap();
+
+        assert_eq!(Some("26"), inputs.answers().part(1));
+        assert_eq!(Ok(Some(10)), inputs.parameters().get::<i32>("row"));
+        assert_eq!(["size"], inputs.ignored_parameters());
+    }
+
+    #[test]
+    fn resolve_test_answers_err() {
+        let root = TempRoot::new("answers-invalid");
+        root.write("input.txt", "actual");
+        root.write("answers.toml", "part_one = ");
+
+        let error = InputResolver::new(&root.0).resolve(2016, 2).unwrap_err();
+
+        assert_eq!(io::ErrorKind::InvalidData, error.source.kind());
+        assert!(error.file.ends_with("answers.toml"));
+    }
+
+    #[test]
+    fn resolve_test_examples_err() {
+        let root = TempRoot::new("examples-invalid");
+        root.write("input.txt", "actual");
+        root.write("example.txt", "example");
+        root.write("example.toml", "part_one = ");
+
+        let error = InputResolver::new(&root.0).resolve(2016, 2).unwrap_err();
+
+        assert_eq!(io::ErrorKind::InvalidData, error.source.kind());
+        assert!(error.file.ends_with("example.toml"));
+    }
+
+    #[test]
+    fn resolve_test_parameters() {
+        let root = TempRoot::new("parameters");
+        root.write("input.txt", "actual");
+        root.write("answers.toml", "[parameters]\nrow = 2_000_000\n");
+        root.write("example.txt", "example");
+        root.write("example.toml", "part_one = 26\n\n[parameters]\nrow = 10\n");
+
+        let inputs = InputResolver::new(&root.0).resolve(2016, 2).unwrap();
+
+        assert_eq!(Ok(Some(2_000_000)), inputs.parameters().get::<i32>("row"));
+        assert_eq!(
+            Ok(Some(10)),
+            inputs.examples()[0].parameters().get::<i32>("row")
+        );
+        assert!(inputs.ignored_parameters().is_empty());
+    }
+
+    #[rstest]
+    #[case("", InputConfig::default())]
+    #[case(
+        "part_one = 24000\npart_two = 45000\n",
+        InputConfig::new(Expected::new(Some("24000"), Some("45000")), Parameters::new())
+    )]
+    #[case(
+        "part_two = \"MCD\"\n[parameters]\nknots = 10\nname = \"rope\"\n",
+        InputConfig::new(
+            Expected::new(None, Some("MCD")),
+            Parameters::new().with("knots", 10).with("name", "rope")
+        )
+    )]
+    fn from_toml_test(#[case] content: &str, #[case] expected: InputConfig) {
+        assert_eq!(expected, InputConfig::from_toml(content).unwrap());
+    }
+
+    #[rstest]
+    #[case("row", Some(10))]
+    #[case("column", None)]
+    fn parameters_get_test(#[case] key: &str, #[case] expected: Option<u32>) {
+        let parameters = Parameters::new().with("row", 10);
+
+        assert_eq!(Ok(expected), parameters.get(key));
+        assert_eq!(Ok(expected.unwrap_or(7)), parameters.get_or(key, 7));
+    }
+
+    #[test]
+    fn parameters_get_test_invalid() {
+        let parameters = Parameters::new().with("row", "ten");
+
+        let expected = InvalidParameterError {
+            key: "row".to_string(),
+            value: "ten".to_string(),
+        };
+        assert_eq!(Err(expected.clone()), parameters.get::<u32>("row"));
+        assert_eq!(Err(expected), parameters.get_or::<u32>("row", 7));
+    }
 }
@@ -1,48 +1,210 @@
-use std::fmt::Display;
+use crate::aoc::{Example, Expected, Parameters, Solution, SolvePartError};
 
-use crate::aoc::Inputs;
-
-pub struct Part<'a, T> {
+pub struct Part<S: Solution> {
     number: u8,
-    inputs: &'a Inputs,
-    solve: fn(&str) -> T,
+    solve: fn(&S::Input, &Parameters) -> Result<String, S::SolveError>,
 }
 
-impl<'a, T> Part<'a, T>
-where
-    T: PartialEq + Display,
-{
-    pub fn one(inputs: &'a Inputs, solve: fn(&str) -> T) -> Self {
+impl<S: Solution> Part<S> {
+    pub fn one() -> Self {
         Self {
             number: 1,
-            inputs,
-            solve,
+            solve: |input, parameters| Ok(S::part_one(input, parameters)?.to_string()),
         }
     }
 
-    pub fn two(inputs: &'a Inputs, solve: fn(&str) -> T) -> Self {
+    pub fn two() -> Self {
         Self {
             number: 2,
-            inputs,
-            solve,
+            solve: |input, parameters| Ok(S::part_two(input, parameters)?.to_string()),
         }
     }
 
-    pub fn test_protocol(&self, expected: T) -> String {
-        let actual = (self.solve)(self.inputs.example());
-        let conclusion = if actual == expected { "PASS" } else { "FAIL" };
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    pub fn test_protocol(
+        &self,
+        examples: &[(&Example, S::Input)],
+    ) -> Result<String, SolvePartError> {
+        Ok(examples
+            .iter()
+            .map(|(example, input)| self.example_protocol(example, input))
+            .collect::<Result<Vec<_>, _>>()?
+            .join("\n"))
+    }
+
+    fn example_protocol(
+        &self,
+        example: &Example,
+        input: &S::Input,
+    ) -> Result<String, SolvePartError> {
+        let actual = self.solve(input, example.parameters())?;
+        let expected = example.expected().part(self.number);
+        let conclusion = match expected {
+            Some(expected) if actual == expected => "PASS",
+            Some(_) => "FAIL",
+            None => "NONE",
+        };
+
+        let lines = [
+            format!("Example `{name}`:", name = example.name()),
+            format!("Expected:\n{expected}", expected = expected.unwrap_or("?")),
+            format!("Actual:\n{actual}"),
+            format!("---- {conclusion} ----"),
+        ];
+
+        Ok(lines.join("\n"))
+    }
+
+    pub fn run(
+        &self,
+        input: &S::Input,
+        parameters: &Parameters,
+        answers: &Expected,
+    ) -> Result<String, SolvePartError> {
+        let actual = self.solve(input, parameters)?;
+        let recorded = answers.part(self.number);
+        let conclusion = match recorded {
+            Some(recorded) if actual == recorded => "MATCH",
+            Some(_) => "DIFFERS",
+            None => "NONE",
+        };
 
         let lines = [
-            format!("--- PART {number} ---", number = self.number),
-            format!("Expected:\n{expected}"),
             format!("Actual:\n{actual}"),
+            format!("Recorded:\n{recorded}", recorded = recorded.unwrap_or("?")),
             format!("---- {conclusion} ----"),
         ];
 
-        lines.join("\n")
+        Ok(lines.join("\n"))
+    }
+
+    pub fn solve(
+        &self,
+        input: &S::Input,
+        parameters: &Parameters,
+    ) -> Result<String, SolvePartError> {
+        (self.solve)(input, parameters).map_err(|source| SolvePartError {
+            source: Box::new(source),
+            year: S::YEAR,
+            day: S::DAY,
+            part: self.number,
+        })
+    }
+}
+
+#[cfg(test)]
+mod part_tests {
+    use std::{convert::Infallible, fmt::Display};
+
+    use rstest::rstest;
+
+    use crate::aoc::{InputConfig, InvalidParameterError};
+
+    use super::*;
+
+    struct Length;
+
+    impl Solution for Length {
+        const YEAR: u16 = 0;
+        const DAY: u16 = 0;
+
+        type Input = String;
+        type ParseError = Infallible;
+        type SolveError = InvalidParameterError;
+
+        fn parse(input: &str) -> Result<Self::Input, Self::ParseError> {
+            Ok(input.to_string())
+        }
+
+        fn part_one(
+            input: &Self::Input,
+            parameters: &Parameters,
+        ) -> Result<impl Display, Self::SolveError> {
+            Ok(input.len() * parameters.get_or("factor", 1)?)
+        }
+
+        fn part_two(input: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+            Ok(input.len() * 2)
+        }
     }
 
-    pub fn run(&self) -> T {
-        (self.solve)(self.inputs.actual())
+    #[rstest]
+    #[case(Some("3"), "MATCH")]
+    #[case(Some("4"), "DIFFERS")]
+    #[case(None, "NONE")]
+    fn run_test(#[case] recorded: Option<&str>, #[case] conclusion: &str) {
+        let answers = Expected::new(recorded, None);
+
+        let report = Part::<Length>::one().run(&"abc".to_string(), &Parameters::new(), &answers);
+
+        let recorded = recorded.unwrap_or("?");
+        let expected = format!("Actual:\n3\nRecorded:\n{recorded}\n---- {conclusion} ----");
+        assert_eq!(expected, report.unwrap());
+    }
+
+    #[test]
+    fn run_test_err() {
+        let parameters = Parameters::new().with("factor", "x");
+
+        let error = Part::<Length>::one()
+            .run(&"abc".to_string(), &parameters, &Expected::default())
+            .unwrap_err();
+
+        assert_eq!(1, error.part);
+        assert_eq!(
+            "Part 1 of puzzle `0000/00` could not be solved.\nParameter `factor` has the invalid value `x`.",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_protocol_test() {
+        let pass = Example::new(
+            "example1",
+            "abc",
+            InputConfig::new(Expected::new(Some("3"), Some("6")), Parameters::new()),
+        );
+        let fail = Example::new(
+            "example2",
+            "ab",
+            InputConfig::new(Expected::new(Some("3"), None), Parameters::new()),
+        );
+        let none = Example::new("example3", "a", InputConfig::default());
+        let parameterized = Example::new(
+            "example4",
+            "ab",
+            InputConfig::new(
+                Expected::new(Some("6"), None),
+                Parameters::new().with("factor", 3),
+            ),
+        );
+        let examples = [&pass, &fail, &none, &parameterized]
+            .map(|example| (example, example.content().to_string()));
+
+        let protocol = Part::<Length>::one().test_protocol(&examples);
+
+        let expected = [
+            "Example `example1`:",
+            "Expected:\n3",
+            "Actual:\n3",
+            "---- PASS ----",
+            "Example `example2`:",
+            "Expected:\n3",
+            "Actual:\n2",
+            "---- FAIL ----",
+            "Example `example3`:",
+            "Expected:\n?",
+            "Actual:\n1",
+            "---- NONE ----",
+            "Example `example4`:",
+            "Expected:\n6",
+            "Actual:\n6",
+            "---- PASS ----",
+        ]
+        .join("\n");
+        assert_eq!(expected, protocol.unwrap());
     }
 }
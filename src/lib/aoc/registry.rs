@@ -0,0 +1,159 @@
+use std::{fmt::Display, num::NonZeroUsize};
+
+use crate::aoc::{self, Benchmark, Inputs, RunError, Solution};
+
+pub type Runner = fn(&Inputs) -> Result<(), RunError>;
+pub type Bencher = fn(&Inputs, NonZeroUsize) -> Result<Benchmark, RunError>;
+
+#[derive(Debug, thiserror::Error)]
+#[error("No solution is registered for puzzle `{year:0>4}/{day:0>2}`.")]
+pub struct UnregisteredPuzzleError {
+    pub year: u16,
+    pub day: u16,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Puzzle {
+    year: u16,
+    day: u16,
+    runner: Runner,
+    bencher: Bencher,
+}
+
+impl Puzzle {
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn day(&self) -> u16 {
+        self.day
+    }
+
+    pub fn run(&self, inputs: &Inputs) -> Result<(), RunError> {
+        (self.runner)(inputs)
+    }
+
+    pub fn bench(&self, inputs: &Inputs, iterations: NonZeroUsize) -> Result<Benchmark, RunError> {
+        (self.bencher)(inputs, iterations)
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{year:0>4}/{day:0>2}", year = self.year, day = self.day)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Registry {
+    puzzles: Vec<Puzzle>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<S: Solution>(&mut self) {
+        let (year, day) = (S::YEAR, S::DAY);
+        self.puzzles
+            .retain(|puzzle| (puzzle.year, puzzle.day) != (year, day));
+        self.puzzles.push(Puzzle {
+            year,
+            day,
+            runner: aoc::run::<S>,
+            bencher: aoc::bench::<S>,
+        });
+        self.puzzles.sort_by_key(|puzzle| (puzzle.year, puzzle.day));
+    }
+
+    pub fn find(&self, year: u16, day: u16) -> Result<&Puzzle, UnregisteredPuzzleError> {
+        self.puzzles
+            .iter()
+            .find(|puzzle| (puzzle.year, puzzle.day) == (year, day))
+            .ok_or(UnregisteredPuzzleError { year, day })
+    }
+
+    pub fn puzzles(&self) -> impl Iterator<Item = &Puzzle> {
+        self.puzzles.iter()
+    }
+}
+
+#[cfg(test)]
+mod registry_tests {
+    use std::convert::Infallible;
+
+    use crate::aoc::Parameters;
+
+    use super::*;
+
+    struct Dummy<const YEAR: u16, const DAY: u16>;
+
+    impl<const YEAR: u16, const DAY: u16> Solution for Dummy<YEAR, DAY> {
+        const YEAR: u16 = YEAR;
+        const DAY: u16 = DAY;
+
+        type Input = ();
+        type ParseError = Infallible;
+        type SolveError = Infallible;
+
+        fn parse(_: &str) -> Result<Self::Input, Self::ParseError> {
+            Ok(())
+        }
+
+        fn part_one(_: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+            Ok(0)
+        }
+
+        fn part_two(_: &Self::Input, _: &Parameters) -> Result<impl Display, Self::SolveError> {
+            Ok(0)
+        }
+    }
+
+    #[test]
+    fn register_test_sorted() {
+        let mut registry = Registry::new();
+
+        registry.register::<Dummy<2022, 1>>();
+        registry.register::<Dummy<2015, 6>>();
+        registry.register::<Dummy<2015, 1>>();
+
+        let puzzles = registry
+            .puzzles()
+            .map(|puzzle| (puzzle.year(), puzzle.day()))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(2015, 1), (2015, 6), (2022, 1)], puzzles);
+    }
+
+    #[test]
+    fn register_test_replaces_duplicate() {
+        let mut registry = Registry::new();
+
+        registry.register::<Dummy<2022, 1>>();
+        registry.register::<Dummy<2022, 1>>();
+
+        assert_eq!(1, registry.puzzles().count());
+    }
+
+    #[test]
+    fn find_test_ok() {
+        let mut registry = Registry::new();
+        registry.register::<Dummy<2016, 12>>();
+
+        let puzzle = registry.find(2016, 12).unwrap();
+
+        assert_eq!("2016/12", puzzle.to_string());
+    }
+
+    #[test]
+    fn find_test_err() {
+        let registry = Registry::new();
+
+        let error = registry.find(2022, 7).unwrap_err();
+
+        assert_eq!(
+            "No solution is registered for puzzle `2022/07`.",
+            error.to_string()
+        );
+    }
+}
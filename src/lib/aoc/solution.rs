@@ -0,0 +1,111 @@
+use std::{error::Error, fmt::Display, io};
+
+use crate::{
+    aoc::{time, Inputs, Parameters, Part},
+    image::ImageExport,
+};
+
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u16;
+
+    type Input;
+    type ParseError: Error + 'static;
+    type SolveError: Error + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::ParseError>;
+
+    fn part_one(
+        input: &Self::Input,
+        parameters: &Parameters,
+    ) -> Result<impl Display, Self::SolveError>;
+
+    fn part_two(
+        input: &Self::Input,
+        parameters: &Parameters,
+    ) -> Result<impl Display, Self::SolveError>;
+
+    /// Exports images of the puzzle. [`run`] calls it once after solving the actual input if the
+    /// `images` parameter is set, so the parts stay free of side effects.
+    fn export_images(_input: &Self::Input, _export: &ImageExport) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Input of puzzle `{year:0>4}/{day:0>2}` could not be parsed.\n{source}")]
+pub struct ParseInputError {
+    #[source]
+    pub source: Box<dyn Error>,
+    pub year: u16,
+    pub day: u16,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Part {part} of puzzle `{year:0>4}/{day:0>2}` could not be solved.\n{source}")]
+pub struct SolvePartError {
+    #[source]
+    pub source: Box<dyn Error>,
+    pub year: u16,
+    pub day: u16,
+    pub part: u8,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RunError {
+    #[error(transparent)]
+    Parse(#[from] ParseInputError),
+    #[error(transparent)]
+    Solve(#[from] SolvePartError),
+    #[error("Images of puzzle `{year:0>4}/{day:0>2}` could not be exported.\n{source}")]
+    Export {
+        #[source]
+        source: Box<dyn Error>,
+        year: u16,
+        day: u16,
+    },
+}
+
+pub fn run<S: Solution>(inputs: &Inputs) -> Result<(), RunError> {
+    let examples = inputs
+        .examples()
+        .iter()
+        .map(|example| Ok((example, parse::<S>(example.content())?)))
+        .collect::<Result<Vec<_>, ParseInputError>>()?;
+    let (actual, elapsed) = time(|| parse::<S>(inputs.actual()));
+    let actual = actual?;
+    println!("Parsed actual input in {elapsed:.2?}.");
+
+    for part in [Part::<S>::one(), Part::<S>::two()] {
+        println!("--- PART {number} ---", number = part.number());
+        if !examples.is_empty() {
+            println!("{protocol}", protocol = part.test_protocol(&examples)?);
+        }
+        let (report, elapsed) = time(|| part.run(&actual, inputs.parameters(), inputs.answers()));
+        println!("{report}\nSolved in {elapsed:.2?}.", report = report?);
+    }
+
+    export_images::<S>(&actual, inputs.parameters())
+}
+
+fn export_images<S: Solution>(input: &S::Input, parameters: &Parameters) -> Result<(), RunError> {
+    let export_error = |source: Box<dyn Error>| RunError::Export {
+        source,
+        year: S::YEAR,
+        day: S::DAY,
+    };
+    let Some(export) =
+        ImageExport::from_parameters(parameters).map_err(|err| export_error(Box::new(err)))?
+    else {
+        return Ok(());
+    };
+    S::export_images(input, &export).map_err(|err| export_error(Box::new(err)))
+}
+
+pub(crate) fn parse<S: Solution>(input: &str) -> Result<S::Input, ParseInputError> {
+    S::parse(input).map_err(|source| ParseInputError {
+        source: Box::new(source),
+        year: S::YEAR,
+        day: S::DAY,
+    })
+}
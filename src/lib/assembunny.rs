@@ -0,0 +1,3 @@
+mod runtime;
+
+pub use runtime::*;
@@ -0,0 +1,898 @@
+mod arithmetic;
+mod assembunny;
+mod compiled;
+mod error;
+mod outcome;
+mod registers;
+mod trace;
+
+pub use self::{
+    arithmetic::{Arithmetic, Overflow, OverflowPolicy, WordSize},
+    assembunny::*,
+    error::MissingRegisterError,
+    outcome::{ExecutionOutcome, HaltReason},
+    registers::Registers,
+    trace::TraceEntry,
+};
+use self::{compiled::CompiledAssembunny, error::MissingRegisterSnafu};
+
+/// Runs an [`Assembunny`] program on a register file of `N` registers, by default the four
+/// registers `a` to `d`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeEnvironment<const N: usize = 4> {
+    assembunny: Assembunny,
+    registers: Registers<N>,
+    ip: isize,
+    output: Vec<Word>,
+    step_limit: Option<usize>,
+    arithmetic: Arithmetic,
+    trace: Option<Vec<TraceEntry<N>>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct State<const N: usize> {
+    ip: isize,
+    registers: Registers<N>,
+    assembunny: Assembunny,
+}
+
+impl<const N: usize> State<N> {
+    fn of(runtime_environment: &RuntimeEnvironment<N>) -> Self {
+        Self {
+            ip: runtime_environment.ip,
+            registers: runtime_environment.registers,
+            assembunny: runtime_environment.assembunny.clone(),
+        }
+    }
+
+    fn matches(&self, runtime_environment: &RuntimeEnvironment<N>) -> bool {
+        self.ip == runtime_environment.ip
+            && self.registers == runtime_environment.registers
+            && self.assembunny == runtime_environment.assembunny
+    }
+}
+
+impl RuntimeEnvironment {
+    pub fn load_assembunny(assembunny: Assembunny) -> Result<Self, MissingRegisterError> {
+        Self::load_assembunny_with_initialized_registers(assembunny, Registers::new())
+    }
+}
+
+impl<const N: usize> RuntimeEnvironment<N> {
+    /// Runs the program on the given register file, which can also have a size other than the
+    /// four registers of Assembunny. Fails if the program uses a register outside of it.
+    pub fn load_assembunny_with_initialized_registers(
+        assembunny: Assembunny,
+        registers: Registers<N>,
+    ) -> Result<Self, MissingRegisterError> {
+        let missing_register = assembunny
+            .iter()
+            .enumerate()
+            .flat_map(|(ip, instruction)| {
+                instruction
+                    .register_ids()
+                    .map(move |register_id| (ip, register_id))
+            })
+            .find(|(_, register_id)| !registers.contains(*register_id));
+        if let Some((ip, register_id)) = missing_register {
+            return MissingRegisterSnafu {
+                ip,
+                register_id,
+                count: N,
+            }
+            .fail();
+        }
+
+        Ok(Self {
+            assembunny,
+            registers,
+            ip: 0,
+            output: Vec::new(),
+            step_limit: None,
+            arithmetic: Arithmetic::default(),
+            trace: None,
+        })
+    }
+
+    pub fn with_step_limit(mut self, step_limit: usize) -> Self {
+        self.step_limit = Some(step_limit);
+        self
+    }
+
+    pub fn with_arithmetic(mut self, arithmetic: Arithmetic) -> Self {
+        self.arithmetic = arithmetic;
+        self
+    }
+
+    /// Starts or stops recording every executed instruction. Stopping discards the recorded
+    /// trace.
+    pub fn set_tracing(&mut self, enabled: bool) {
+        match (enabled, &self.trace) {
+            (true, None) => self.trace = Some(Vec::new()),
+            (false, Some(_)) => self.trace = None,
+            _ => {}
+        }
+    }
+
+    pub fn trace(&self) -> &[TraceEntry<N>] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
+    pub fn ip(&self) -> isize {
+        self.ip
+    }
+
+    pub fn register_value(&self, register_id: RegisterId) -> Word {
+        self.registers.register_value(register_id)
+    }
+
+    pub fn registers(&self) -> Registers<N> {
+        self.registers
+    }
+
+    pub fn assembunny(&self) -> &Assembunny {
+        &self.assembunny
+    }
+
+    /// Values transmitted by `out` instructions, in the order they were executed.
+    pub fn output(&self) -> &[Word] {
+        &self.output
+    }
+
+    pub fn take_output(&mut self) -> Vec<Word> {
+        std::mem::take(&mut self.output)
+    }
+
+    /// Runs the program until it halts, the step limit is exhausted or a loop is detected.
+    pub fn run_program(&mut self) -> ExecutionOutcome {
+        self.run_until(|_, _| false)
+    }
+
+    /// Like [`Self::run_program`], but compiles the program first, which makes it a lot faster.
+    /// Nothing is traced. Programs containing `tgl` modify themselves and are interpreted.
+    pub fn run_compiled(&mut self) -> ExecutionOutcome {
+        let Some(compiled) = CompiledAssembunny::compile(&self.assembunny) else {
+            return self.run_program();
+        };
+
+        compiled.run(
+            &mut self.registers,
+            &mut self.ip,
+            &mut self.output,
+            self.step_limit,
+            self.arithmetic,
+        )
+    }
+
+    /// Like [`Self::run_program`], but also stops after the first step for which `breakpoint`
+    /// returns `true`. It receives the environment after the step and the executed instruction.
+    ///
+    /// Loops are detected with Brent's algorithm: the state is saved whenever the number of
+    /// executed steps reaches a power of two and compared to the state after every step.
+    pub fn run_until(
+        &mut self,
+        mut breakpoint: impl FnMut(&Self, &TraceEntry<N>) -> bool,
+    ) -> ExecutionOutcome {
+        let mut steps = 0;
+        let mut saved_state = State::of(self);
+        let mut next_save = 1;
+        loop {
+            if self
+                .step_limit
+                .is_some_and(|step_limit| steps >= step_limit)
+            {
+                return ExecutionOutcome::new(HaltReason::StepLimitReached, steps);
+            }
+            let entry = match self.step() {
+                Ok(entry) => entry,
+                Err(reason) => return ExecutionOutcome::new(reason, steps),
+            };
+            steps += 1;
+
+            if saved_state.matches(self) {
+                return ExecutionOutcome::new(HaltReason::LoopDetected, steps);
+            }
+            if breakpoint(self, &entry) {
+                return ExecutionOutcome::new(HaltReason::BreakpointReached, steps);
+            }
+            if steps == next_save {
+                saved_state = State::of(self);
+                next_save *= 2;
+            }
+        }
+    }
+
+    /// Executes the next instruction. Fails with [`HaltReason::Halted`] if the program has
+    /// already halted and with [`HaltReason::Overflow`] if the instruction overflowed.
+    pub fn step(&mut self) -> Result<TraceEntry<N>, HaltReason> {
+        if !self.is_program_running() {
+            return Err(HaltReason::Halted);
+        }
+
+        let ip = self.ip;
+        let instruction = self.assembunny[ip as usize];
+        let before = self.registers;
+        self.execute_next_instruction()
+            .map_err(|Overflow| HaltReason::Overflow)?;
+        let entry = TraceEntry {
+            ip,
+            instruction,
+            before,
+            after: self.registers,
+        };
+
+        if let Some(trace) = &mut self.trace {
+            trace.push(entry);
+        }
+        Ok(entry)
+    }
+
+    fn execute_next_instruction(&mut self) -> Result<(), Overflow> {
+        match self.assembunny[self.ip as usize] {
+            Instruction::Cpy {
+                from,
+                into: Argument::Reference(into),
+            } => self.execute_cpy_instruction(from, into)?,
+            Instruction::Inc(Argument::Reference(register_id)) => {
+                self.registers.increment(register_id, self.arithmetic)?
+            }
+            Instruction::Dec(Argument::Reference(register_id)) => {
+                self.registers.decrement(register_id, self.arithmetic)?
+            }
+            Instruction::Jnz {
+                condition,
+                jump_offset,
+            } => {
+                self.execute_jnz_instruction(condition, jump_offset);
+                return Ok(());
+            }
+            Instruction::Tgl(offset) => self.execute_tgl_instruction(offset),
+            Instruction::Out(value) => self.output.push(self.dereference_argument(value)),
+            Instruction::Add { from, into } => return self.execute_add_instruction(from, into),
+            Instruction::Mul {
+                from,
+                times,
+                into,
+                scratch,
+            } => return self.execute_mul_instruction(from, times, into, scratch),
+            Instruction::Nop
+            | Instruction::Cpy { .. }
+            | Instruction::Inc(_)
+            | Instruction::Dec(_) => {}
+        }
+        self.jump_to_next_instruction();
+        Ok(())
+    }
+
+    fn execute_cpy_instruction(
+        &mut self,
+        from: Argument,
+        into: RegisterId,
+    ) -> Result<(), Overflow> {
+        *self.registers.register_value_mut(into) = self
+            .arithmetic
+            .fit(self.dereference_argument(from).into())?;
+        Ok(())
+    }
+
+    fn execute_jnz_instruction(&mut self, condition: Argument, jump_offset: Argument) {
+        if self.condition_is_not_zero(condition) {
+            self.jump_to_offset(self.dereference_argument(jump_offset));
+        } else {
+            self.jump_to_next_instruction();
+        }
+    }
+
+    fn execute_tgl_instruction(&mut self, offset: Argument) {
+        let target = self
+            .ip
+            .checked_add(self.dereference_argument(offset) as isize);
+        if let Some(instruction) = target
+            .and_then(|target| usize::try_from(target).ok())
+            .and_then(|target| self.assembunny.get_mut(target))
+        {
+            *instruction = instruction.toggled();
+        }
+    }
+
+    fn execute_add_instruction(
+        &mut self,
+        from: RegisterId,
+        into: RegisterId,
+    ) -> Result<(), Overflow> {
+        if self.registers.register_value(from) > 0 {
+            *self.registers.register_value_mut(into) = self.arithmetic.add(
+                self.registers.register_value(into),
+                self.registers.register_value(from),
+            )?;
+            *self.registers.register_value_mut(from) = 0;
+        }
+        self.execute_jnz_instruction(
+            Argument::Reference(from),
+            Argument::Literal(Instruction::ADD_JUMP_OFFSET),
+        );
+        Ok(())
+    }
+
+    fn execute_mul_instruction(
+        &mut self,
+        from: Argument,
+        times: RegisterId,
+        into: RegisterId,
+        scratch: RegisterId,
+    ) -> Result<(), Overflow> {
+        let (from_value, times_value) = (
+            self.dereference_argument(from),
+            self.registers.register_value(times),
+        );
+        if from_value > 0 && times_value > 0 {
+            *self.registers.register_value_mut(into) = self.arithmetic.mul_add(
+                self.registers.register_value(into),
+                from_value,
+                times_value,
+            )?;
+            *self.registers.register_value_mut(times) = 0;
+            *self.registers.register_value_mut(scratch) = 0;
+        }
+        self.execute_jnz_instruction(
+            Argument::Reference(times),
+            Argument::Literal(Instruction::MUL_JUMP_OFFSET),
+        );
+        Ok(())
+    }
+
+    fn jump_to_offset(&mut self, jump_offset: Word) {
+        self.ip = self.ip.saturating_add(jump_offset as isize);
+    }
+
+    fn condition_is_not_zero(&self, condition: Argument) -> bool {
+        self.dereference_argument(condition) != 0
+    }
+
+    fn jump_to_next_instruction(&mut self) {
+        self.ip += 1;
+    }
+
+    fn dereference_argument(&self, argument: Argument) -> Word {
+        match argument {
+            Argument::Literal(value) => value,
+            Argument::Reference(register_id) => self.registers.register_value(register_id),
+        }
+    }
+
+    fn is_program_running(&self) -> bool {
+        let Assembunny(instructions) = &self.assembunny;
+        usize::try_from(self.ip).is_ok_and(|ip| ip < instructions.len())
+    }
+}
+
+#[cfg(test)]
+mod runtime_environment_tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn assembunny() -> Assembunny {
+        Assembunny(vec![
+            Instruction::Cpy {
+                from: Argument::Literal(41),
+                into: RegisterId::A.into(),
+            },
+            Instruction::Inc(RegisterId::A.into()),
+            Instruction::Inc(RegisterId::A.into()),
+            Instruction::Dec(RegisterId::A.into()),
+            Instruction::Jnz {
+                condition: Argument::Reference(RegisterId::A),
+                jump_offset: 2.into(),
+            },
+            Instruction::Dec(RegisterId::A.into()),
+        ])
+    }
+
+    #[test]
+    fn run_program_test() {
+        let mut runtime_environment = RuntimeEnvironment::load_assembunny(Assembunny(vec![
+            Instruction::Inc(RegisterId::A.into()),
+            Instruction::Inc(RegisterId::B.into()),
+            Instruction::Inc(RegisterId::C.into()),
+            Instruction::Inc(RegisterId::D.into()),
+        ]))
+        .unwrap();
+
+        let outcome = runtime_environment.run_program();
+
+        assert_eq!(4, runtime_environment.ip);
+        assert_eq!(ExecutionOutcome::new(HaltReason::Halted, 4), outcome);
+    }
+
+    #[rstest]
+    #[case(&["inc a", "jnz 1 -1"], HaltReason::StepLimitReached, 100)]
+    #[case(&["cpy 3 a", "dec a", "jnz a -1"], HaltReason::Halted, 7)]
+    #[case(&["cpy 1 a", "jnz a 0"], HaltReason::LoopDetected, 2)]
+    #[case(&["cpy 2 a", "inc a", "dec a", "jnz a -2"], HaltReason::LoopDetected, 7)]
+    #[case(&["tgl 2", "jnz 1 -1", "inc a"], HaltReason::LoopDetected, 8)]
+    #[case(&["inc a", "jnz 1 -2"], HaltReason::Halted, 2)]
+    fn run_program_test_outcome(
+        #[case] program: &[&str],
+        #[case] expected_reason: HaltReason,
+        #[case] expected_steps: usize,
+    ) {
+        let mut runtime_environment =
+            RuntimeEnvironment::load_assembunny(program.join("\n").parse().unwrap())
+                .unwrap()
+                .with_step_limit(100);
+
+        let outcome = runtime_environment.run_program();
+
+        assert_eq!(
+            ExecutionOutcome::new(expected_reason, expected_steps),
+            outcome
+        );
+    }
+
+    #[rstest]
+    #[case(WordSize::Bits32, OverflowPolicy::Checked, HaltReason::Overflow, [i32::MAX.into(), 0, 0, 0])]
+    #[case(WordSize::Bits32, OverflowPolicy::Wrapping, HaltReason::Halted, [i32::MIN.into(), 1, 0, 0])]
+    #[case(WordSize::Bits32, OverflowPolicy::Saturating, HaltReason::Halted, [i32::MAX.into(), 1, 0, 0])]
+    #[case(WordSize::Bits64, OverflowPolicy::Checked, HaltReason::Halted, [Word::from(i32::MAX) + 1, 1, 0, 0])]
+    fn run_program_test_arithmetic(
+        #[case] word_size: WordSize,
+        #[case] overflow_policy: OverflowPolicy,
+        #[case] expected_reason: HaltReason,
+        #[case] expected_registers: [Word; 4],
+    ) {
+        let load = || {
+            RuntimeEnvironment::load_assembunny(
+                ["cpy 2147483646 a", "inc a", "inc a", "inc b"]
+                    .join("\n")
+                    .parse()
+                    .unwrap(),
+            )
+            .unwrap()
+            .with_arithmetic(Arithmetic::new(word_size, overflow_policy))
+        };
+        let mut interpreted = load();
+        let mut compiled = load();
+
+        let outcome = interpreted.run_program();
+
+        assert_eq!(expected_reason, outcome.reason);
+        assert_eq!(Registers::from(expected_registers), interpreted.registers());
+        assert_eq!(outcome, compiled.run_compiled());
+        assert_eq!(interpreted, compiled);
+    }
+
+    #[test]
+    fn run_program_test_register_file() {
+        let assembunny = "cpy a b\ninc b\njnz a 2\ninc a".parse().unwrap();
+        let load = || {
+            RuntimeEnvironment::load_assembunny_with_initialized_registers(
+                Assembunny::clone(&assembunny),
+                Registers::from([1, 0]),
+            )
+            .unwrap()
+        };
+        let mut interpreted = load();
+        let mut compiled = load();
+
+        interpreted.run_program();
+        compiled.run_compiled();
+
+        assert_eq!(Registers::from([1, 2]), interpreted.registers());
+        assert_eq!(interpreted, compiled);
+    }
+
+    #[test]
+    fn load_assembunny_with_initialized_registers_test_missing_register() {
+        let assembunny = "inc a\ninc c".parse().unwrap();
+
+        let error = RuntimeEnvironment::load_assembunny_with_initialized_registers(
+            assembunny,
+            Registers::<2>::new(),
+        )
+        .unwrap_err();
+
+        assert_eq!(1, error.ip());
+        assert_eq!(RegisterId::C, error.register_id());
+        assert_eq!(
+            "Instruction 1 uses the register `c`, but there are only 2 registers.",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn load_assembunny_test_missing_register() {
+        let assembunny = "cpy 1 e".parse().unwrap();
+
+        assert!(RuntimeEnvironment::load_assembunny(assembunny).is_err());
+    }
+
+    #[test]
+    fn step_test_overflow() {
+        let mut runtime_environment =
+            RuntimeEnvironment::load_assembunny_with_initialized_registers(
+                Assembunny(vec![Instruction::Dec(RegisterId::A.into())]),
+                [i32::MIN.into(), 0, 0, 0].into(),
+            )
+            .unwrap();
+
+        assert_eq!(Err(HaltReason::Overflow), runtime_environment.step());
+        assert_eq!(0, runtime_environment.ip());
+    }
+
+    #[test]
+    fn step_test() {
+        let mut runtime_environment =
+            RuntimeEnvironment::load_assembunny(Assembunny(vec![Instruction::Cpy {
+                from: 3.into(),
+                into: RegisterId::B.into(),
+            }]))
+            .unwrap();
+
+        let entry = runtime_environment.step();
+
+        assert_eq!(
+            Ok(TraceEntry {
+                ip: 0,
+                instruction: runtime_environment.assembunny[0],
+                before: Registers::new(),
+                after: [0, 3, 0, 0].into(),
+            }),
+            entry
+        );
+        assert_eq!(1, runtime_environment.ip());
+        assert_eq!(Err(HaltReason::Halted), runtime_environment.step());
+    }
+
+    #[test]
+    fn run_until_test() {
+        let mut runtime_environment = RuntimeEnvironment::load_assembunny(
+            ["cpy 5 b", "inc a", "dec b", "jnz b -2", "inc c"]
+                .join("\n")
+                .parse()
+                .unwrap(),
+        )
+        .unwrap();
+
+        let outcome = runtime_environment.run_until(|runtime_environment, _| {
+            runtime_environment.register_value(RegisterId::A) == 3
+        });
+
+        assert_eq!(
+            ExecutionOutcome::new(HaltReason::BreakpointReached, 8),
+            outcome
+        );
+        assert_eq!(2, runtime_environment.ip());
+        assert_eq!(
+            Registers::from([3, 3, 0, 0]),
+            runtime_environment.registers()
+        );
+
+        let outcome = runtime_environment.run_until(|_, entry| entry.ip == 4);
+
+        assert_eq!(
+            ExecutionOutcome::new(HaltReason::BreakpointReached, 9),
+            outcome
+        );
+        assert_eq!(
+            Registers::from([5, 0, 1, 0]),
+            runtime_environment.registers()
+        );
+    }
+
+    #[test]
+    fn set_tracing_test() {
+        let mut runtime_environment =
+            RuntimeEnvironment::load_assembunny(["inc a", "dec b"].join("\n").parse().unwrap())
+                .unwrap();
+        runtime_environment.step().unwrap();
+        runtime_environment.set_tracing(true);
+
+        runtime_environment.run_program();
+
+        assert_eq!(
+            [TraceEntry {
+                ip: 1,
+                instruction: Instruction::Dec(RegisterId::B.into()),
+                before: [1, 0, 0, 0].into(),
+                after: [1, -1, 0, 0].into(),
+            }],
+            runtime_environment.trace()
+        );
+
+        runtime_environment.set_tracing(false);
+
+        assert!(runtime_environment.trace().is_empty());
+    }
+
+    #[test]
+    fn execute_next_instruction_test_cpy_literal() {
+        let mut runtime_environment = runtime_environment_with_instruction(Instruction::Cpy {
+            from: Argument::Literal(5),
+            into: RegisterId::A.into(),
+        });
+
+        runtime_environment.execute_next_instruction().unwrap();
+
+        assert_eq!(
+            5,
+            runtime_environment.registers.register_value(RegisterId::A)
+        );
+    }
+
+    #[test]
+    fn execute_next_instruction_test_cpy_reference() {
+        let mut runtime_environment = runtime_environment_with_instruction(Instruction::Cpy {
+            from: Argument::Reference(RegisterId::B),
+            into: RegisterId::A.into(),
+        });
+        *runtime_environment
+            .registers
+            .register_value_mut(RegisterId::B) = 5;
+
+        runtime_environment.execute_next_instruction().unwrap();
+
+        assert_eq!(
+            5,
+            runtime_environment.registers.register_value(RegisterId::A)
+        );
+    }
+
+    #[test]
+    fn execute_next_instruction_test_inc() {
+        let mut runtime_environment =
+            runtime_environment_with_instruction(Instruction::Inc(RegisterId::A.into()));
+
+        runtime_environment.execute_next_instruction().unwrap();
+
+        assert_eq!(
+            1,
+            runtime_environment.registers.register_value(RegisterId::A)
+        );
+    }
+
+    #[test]
+    fn execute_next_instruction_test_dec() {
+        let mut runtime_environment =
+            runtime_environment_with_instruction(Instruction::Dec(RegisterId::A.into()));
+
+        runtime_environment.execute_next_instruction().unwrap();
+
+        assert_eq!(
+            -1,
+            runtime_environment.registers.register_value(RegisterId::A)
+        );
+    }
+
+    #[test]
+    fn execute_next_instruction_test_jnz_condition_true() {
+        let condition_register_id = RegisterId::A;
+        let jump_offset = 5;
+        let mut runtime_environment = runtime_environment_with_instruction(Instruction::Jnz {
+            condition: Argument::Reference(condition_register_id),
+            jump_offset: jump_offset.into(),
+        });
+        *runtime_environment
+            .registers
+            .register_value_mut(condition_register_id) = 1;
+
+        runtime_environment.execute_next_instruction().unwrap();
+
+        assert_eq!(jump_offset as isize, runtime_environment.ip);
+    }
+
+    #[test]
+    fn execute_next_instruction_test_jnz_condition_false() {
+        let mut runtime_environment = runtime_environment_with_instruction(Instruction::Jnz {
+            condition: Argument::Reference(RegisterId::A),
+            jump_offset: 5.into(),
+        });
+
+        runtime_environment.execute_next_instruction().unwrap();
+
+        assert_eq!(1, runtime_environment.ip);
+    }
+
+    #[test]
+    fn execute_next_instruction_test_jnz_reference_offset() {
+        let mut runtime_environment = runtime_environment_with_instruction(Instruction::Jnz {
+            condition: Argument::Literal(1),
+            jump_offset: RegisterId::B.into(),
+        });
+        *runtime_environment
+            .registers
+            .register_value_mut(RegisterId::B) = 3;
+
+        runtime_environment.execute_next_instruction().unwrap();
+
+        assert_eq!(3, runtime_environment.ip);
+    }
+
+    #[rstest]
+    #[case(Instruction::Cpy { from: 1.into(), into: 2.into() })]
+    #[case(Instruction::Inc(1.into()))]
+    #[case(Instruction::Dec(1.into()))]
+    fn execute_next_instruction_test_invalid_skipped(#[case] instruction: Instruction) {
+        let mut runtime_environment = runtime_environment_with_instruction(instruction);
+
+        runtime_environment.execute_next_instruction().unwrap();
+
+        assert_eq!(1, runtime_environment.ip);
+        assert_eq!(Registers::new(), runtime_environment.registers);
+    }
+
+    #[test]
+    fn execute_next_instruction_test_out() {
+        let mut runtime_environment =
+            runtime_environment_with_instruction(Instruction::Out(RegisterId::A.into()));
+        *runtime_environment
+            .registers
+            .register_value_mut(RegisterId::A) = 7;
+
+        runtime_environment.execute_next_instruction().unwrap();
+
+        assert_eq!(1, runtime_environment.ip);
+        assert_eq!(vec![7], runtime_environment.take_output());
+        assert!(runtime_environment.output().is_empty());
+    }
+
+    #[rstest]
+    #[case(0, Instruction::Inc(0.into()))]
+    #[case(5, Instruction::Tgl(5.into()))]
+    #[case(-1, Instruction::Tgl((-1).into()))]
+    fn execute_next_instruction_test_tgl(#[case] offset: Word, #[case] expected: Instruction) {
+        let mut runtime_environment =
+            runtime_environment_with_instruction(Instruction::Tgl(offset.into()));
+
+        runtime_environment.execute_next_instruction().unwrap();
+
+        assert_eq!(1, runtime_environment.ip);
+        assert_eq!(expected, runtime_environment.assembunny[0]);
+    }
+
+    #[test]
+    fn run_program_test_tgl() {
+        let mut runtime_environment = RuntimeEnvironment::load_assembunny(
+            [
+                "cpy 2 a", "tgl a", "tgl a", "tgl a", "cpy 1 a", "dec a", "dec a",
+            ]
+            .join("\n")
+            .parse()
+            .unwrap(),
+        )
+        .unwrap();
+
+        runtime_environment.run_program();
+
+        assert_eq!(3, runtime_environment.register_value(RegisterId::A));
+    }
+
+    #[test]
+    fn execute_next_instruction_test_add() {
+        let mut runtime_environment = runtime_environment_with_instruction(Instruction::Add {
+            from: RegisterId::B,
+            into: RegisterId::A,
+        });
+        runtime_environment.registers = [2, 3, 0, 0].into();
+
+        runtime_environment.execute_next_instruction().unwrap();
+
+        assert_eq!(Registers::from([5, 0, 0, 0]), runtime_environment.registers);
+        assert_eq!(1, runtime_environment.ip);
+    }
+
+    #[test]
+    fn execute_next_instruction_test_mul() {
+        let mut runtime_environment = runtime_environment_with_instruction(Instruction::Mul {
+            from: RegisterId::B.into(),
+            times: RegisterId::D,
+            into: RegisterId::A,
+            scratch: RegisterId::C,
+        });
+        runtime_environment.registers = [1, 3, 7, 4].into();
+
+        runtime_environment.execute_next_instruction().unwrap();
+
+        assert_eq!(
+            Registers::from([13, 3, 0, 0]),
+            runtime_environment.registers
+        );
+        assert_eq!(1, runtime_environment.ip);
+    }
+
+    #[rstest]
+    #[case(Instruction::Add { from: RegisterId::B, into: RegisterId::A }, [2, -3, 0, 0], -2)]
+    #[case(Instruction::Add { from: RegisterId::B, into: RegisterId::A }, [2, 0, 0, 0], 1)]
+    #[case(Instruction::Mul { from: RegisterId::B.into(), times: RegisterId::D, into: RegisterId::A, scratch: RegisterId::C }, [1, -3, 0, 4], -5)]
+    #[case(Instruction::Mul { from: RegisterId::B.into(), times: RegisterId::D, into: RegisterId::A, scratch: RegisterId::C }, [1, 3, 0, -4], -5)]
+    fn execute_next_instruction_test_not_positive(
+        #[case] instruction: Instruction,
+        #[case] registers: [Word; 4],
+        #[case] expected_ip: isize,
+    ) {
+        let mut runtime_environment = runtime_environment_with_instruction(instruction);
+        runtime_environment.registers = registers.into();
+
+        runtime_environment.execute_next_instruction().unwrap();
+
+        assert_eq!(Registers::from(registers), runtime_environment.registers);
+        assert_eq!(expected_ip, runtime_environment.ip);
+    }
+
+    #[test]
+    fn execute_next_instruction_test_nop() {
+        let mut runtime_environment = runtime_environment_with_instruction(Instruction::Nop);
+
+        runtime_environment.execute_next_instruction().unwrap();
+
+        assert_eq!(Registers::new(), runtime_environment.registers);
+        assert_eq!(1, runtime_environment.ip);
+    }
+
+    fn runtime_environment_with_instruction(instruction: Instruction) -> RuntimeEnvironment {
+        RuntimeEnvironment {
+            assembunny: Assembunny(vec![instruction]),
+            registers: Registers::new(),
+            ip: 0,
+            output: Vec::new(),
+            step_limit: None,
+            arithmetic: Arithmetic::default(),
+            trace: None,
+        }
+    }
+
+    #[rstest]
+    #[case(RegisterId::A)]
+    #[case(RegisterId::B)]
+    #[case(RegisterId::C)]
+    #[case(RegisterId::D)]
+    fn dereference_argument_test_argument_reference(#[case] register_id: RegisterId) {
+        let mut runtime_environment =
+            RuntimeEnvironment::load_assembunny(Assembunny(vec![])).unwrap();
+        *runtime_environment
+            .registers
+            .register_value_mut(register_id) = 5;
+
+        let argument_value =
+            runtime_environment.dereference_argument(Argument::Reference(register_id));
+
+        assert_eq!(5, argument_value);
+    }
+
+    #[rstest]
+    #[case(5)]
+    #[case(-5)]
+    fn dereference_argument_test_argument_literal(#[case] word: Word) {
+        let mut runtime_environment =
+            RuntimeEnvironment::load_assembunny(Assembunny(vec![])).unwrap();
+        *runtime_environment
+            .registers
+            .register_value_mut(RegisterId::A) = word;
+
+        let argument_value =
+            runtime_environment.dereference_argument(Argument::Reference(RegisterId::A));
+
+        assert_eq!(word, argument_value);
+    }
+
+    #[rstest]
+    #[case(false, 6)]
+    #[case(false, 100)]
+    #[case(false, -1)]
+    #[case(true, 0)]
+    #[case(true, 4)]
+    fn is_program_running_test(assembunny: Assembunny, #[case] expected: bool, #[case] ip: isize) {
+        let runtime_environment: RuntimeEnvironment = RuntimeEnvironment {
+            assembunny,
+            registers: Registers::new(),
+            ip,
+            output: Vec::new(),
+            step_limit: None,
+            arithmetic: Arithmetic::default(),
+            trace: None,
+        };
+
+        assert_eq!(expected, runtime_environment.is_program_running());
+    }
+}
@@ -0,0 +1,164 @@
+use super::Word;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WordSize {
+    #[default]
+    Bits32,
+    Bits64,
+}
+
+impl WordSize {
+    fn bounds(self) -> (i128, i128) {
+        match self {
+            Self::Bits32 => (i32::MIN.into(), i32::MAX.into()),
+            Self::Bits64 => (i64::MIN.into(), i64::MAX.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    Wrapping,
+    /// Stops the program with [`HaltReason::Overflow`](super::HaltReason::Overflow).
+    #[default]
+    Checked,
+    Saturating,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+/// Determines the range of a [`Word`] and what happens if a result does not fit into it. Words
+/// are always stored as `i64`, the word size only restricts their values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Arithmetic {
+    pub word_size: WordSize,
+    pub overflow_policy: OverflowPolicy,
+}
+
+impl Arithmetic {
+    pub fn new(word_size: WordSize, overflow_policy: OverflowPolicy) -> Self {
+        Self {
+            word_size,
+            overflow_policy,
+        }
+    }
+
+    pub fn add(self, augend: Word, addend: Word) -> Result<Word, Overflow> {
+        self.fit(i128::from(augend) + i128::from(addend))
+    }
+
+    pub fn sub(self, minuend: Word, subtrahend: Word) -> Result<Word, Overflow> {
+        self.fit(i128::from(minuend) - i128::from(subtrahend))
+    }
+
+    pub fn mul(self, multiplier: Word, multiplicand: Word) -> Result<Word, Overflow> {
+        self.fit(i128::from(multiplier) * i128::from(multiplicand))
+    }
+
+    /// `augend + multiplier * multiplicand`, with the overflow policy applied only to the
+    /// result, like a loop that adds one at a time would do for positive factors.
+    pub fn mul_add(
+        self,
+        augend: Word,
+        multiplier: Word,
+        multiplicand: Word,
+    ) -> Result<Word, Overflow> {
+        self.fit(i128::from(augend) + i128::from(multiplier) * i128::from(multiplicand))
+    }
+
+    /// Applies the overflow policy to a value that may lie outside of the word size.
+    pub fn fit(self, value: i128) -> Result<Word, Overflow> {
+        let (min, max) = self.word_size.bounds();
+        if (min..=max).contains(&value) {
+            return Ok(value as Word);
+        }
+
+        match self.overflow_policy {
+            OverflowPolicy::Wrapping => Ok(match self.word_size {
+                WordSize::Bits32 => value as i32 as Word,
+                WordSize::Bits64 => value as i64,
+            }),
+            OverflowPolicy::Checked => Err(Overflow),
+            OverflowPolicy::Saturating => Ok(value.clamp(min, max) as Word),
+        }
+    }
+}
+
+#[cfg(test)]
+mod arithmetic_tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const MAX_32: Word = i32::MAX as Word;
+    const MIN_32: Word = i32::MIN as Word;
+
+    #[rstest]
+    #[case(WordSize::Bits32, OverflowPolicy::Checked, 1, Ok(MAX_32))]
+    #[case(WordSize::Bits32, OverflowPolicy::Checked, 2, Err(Overflow))]
+    #[case(WordSize::Bits32, OverflowPolicy::Wrapping, 2, Ok(MIN_32))]
+    #[case(WordSize::Bits32, OverflowPolicy::Saturating, 2, Ok(MAX_32))]
+    #[case(WordSize::Bits64, OverflowPolicy::Checked, 2, Ok(MAX_32 + 1))]
+    fn add_test(
+        #[case] word_size: WordSize,
+        #[case] overflow_policy: OverflowPolicy,
+        #[case] addend: Word,
+        #[case] expected: Result<Word, Overflow>,
+    ) {
+        let arithmetic = Arithmetic::new(word_size, overflow_policy);
+
+        assert_eq!(expected, arithmetic.add(MAX_32 - 1, addend));
+    }
+
+    #[rstest]
+    #[case(OverflowPolicy::Checked, Err(Overflow))]
+    #[case(OverflowPolicy::Wrapping, Ok(i64::MAX))]
+    #[case(OverflowPolicy::Saturating, Ok(i64::MIN))]
+    fn sub_test(#[case] overflow_policy: OverflowPolicy, #[case] expected: Result<Word, Overflow>) {
+        let arithmetic = Arithmetic::new(WordSize::Bits64, overflow_policy);
+
+        assert_eq!(expected, arithmetic.sub(i64::MIN, 1));
+    }
+
+    #[rstest]
+    #[case(WordSize::Bits32, OverflowPolicy::Checked, Err(Overflow))]
+    #[case(WordSize::Bits32, OverflowPolicy::Wrapping, Ok(0))]
+    #[case(WordSize::Bits32, OverflowPolicy::Saturating, Ok(MIN_32))]
+    #[case(WordSize::Bits64, OverflowPolicy::Checked, Ok(-(1 << 32)))]
+    fn mul_test(
+        #[case] word_size: WordSize,
+        #[case] overflow_policy: OverflowPolicy,
+        #[case] expected: Result<Word, Overflow>,
+    ) {
+        let arithmetic = Arithmetic::new(word_size, overflow_policy);
+
+        assert_eq!(expected, arithmetic.mul(1 << 16, -(1 << 16)));
+    }
+
+    #[rstest]
+    #[case(OverflowPolicy::Checked, Ok(2_147_449_184))]
+    #[case(OverflowPolicy::Wrapping, Ok(2_147_449_184))]
+    #[case(OverflowPolicy::Saturating, Ok(2_147_449_184))]
+    fn mul_add_test_intermediate_overflow(
+        #[case] overflow_policy: OverflowPolicy,
+        #[case] expected: Result<Word, Overflow>,
+    ) {
+        let arithmetic = Arithmetic::new(WordSize::Bits32, overflow_policy);
+
+        assert_eq!(expected, arithmetic.mul_add(-100_000, 65_536, 32_769));
+    }
+
+    #[rstest]
+    #[case(OverflowPolicy::Checked, Err(Overflow))]
+    #[case(OverflowPolicy::Wrapping, Ok(MIN_32 + 4))]
+    #[case(OverflowPolicy::Saturating, Ok(MAX_32))]
+    fn mul_add_test_overflow(
+        #[case] overflow_policy: OverflowPolicy,
+        #[case] expected: Result<Word, Overflow>,
+    ) {
+        let arithmetic = Arithmetic::new(WordSize::Bits32, overflow_policy);
+
+        assert_eq!(expected, arithmetic.mul_add(MAX_32 - 1, 2, 3));
+    }
+}
@@ -0,0 +1,248 @@
+mod control_flow;
+mod error;
+mod instruction;
+mod listing;
+mod optimization;
+
+use snafu::prelude::*;
+
+use std::{
+    fmt::Display,
+    ops::{Deref, DerefMut},
+    str::FromStr,
+};
+
+use self::error::AssembunnyParseSnafu;
+pub use self::{
+    control_flow::{lint, BasicBlock, ControlFlowGraph, LintWarning, Successor},
+    error::{AssembunnyParseError, InvalidLine},
+    instruction::*,
+    listing::Listing,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assembunny(pub Vec<Instruction>);
+
+impl Deref for Assembunny {
+    type Target = Vec<Instruction>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Assembunny {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Display for Assembunny {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, instruction) in self.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{instruction}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Assembunny {
+    type Err = AssembunnyParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut instructions = Vec::new();
+        let mut invalid_lines = Vec::new();
+        for (index, line) in string.lines().enumerate() {
+            match line.parse() {
+                Ok(instruction) => instructions.push(instruction),
+                Err(source) => invalid_lines.push(InvalidLine::new(index + 1, line, source)),
+            }
+        }
+
+        ensure!(
+            invalid_lines.is_empty(),
+            AssembunnyParseSnafu { invalid_lines }
+        );
+        Ok(Self(instructions))
+    }
+}
+
+/// Parses a program given as one instruction per line, so tests can spell it out as a slice.
+#[cfg(test)]
+pub(crate) fn assembunny(program: &[&str]) -> Assembunny {
+    program.join("\n").parse().unwrap()
+}
+
+#[cfg(test)]
+mod assembunny_tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn from_str_trait_from_str_test_ok() {
+        let input = ["cpy 7 c", "inc d", "dec c", "jnz c -2", "tgl a", "out 1"].join("\n");
+        let expected = Ok(Assembunny(vec![
+            Instruction::Cpy {
+                from: Argument::Literal(7),
+                into: Argument::Reference(RegisterId::C),
+            },
+            Instruction::Inc(Argument::Reference(RegisterId::D)),
+            Instruction::Dec(Argument::Reference(RegisterId::C)),
+            Instruction::Jnz {
+                condition: Argument::Reference(RegisterId::C),
+                jump_offset: Argument::Literal(-2),
+            },
+            Instruction::Tgl(Argument::Reference(RegisterId::A)),
+            Instruction::Out(Argument::Literal(1)),
+        ]));
+
+        assert_eq!(expected, input.parse());
+    }
+
+    /// Generates valid instructions with a xorshift generator, so the same programs are
+    /// checked on every run.
+    struct Programs(u64);
+
+    impl Programs {
+        fn next(&mut self, bound: u64) -> u64 {
+            let Self(state) = self;
+            *state ^= *state << 13;
+            *state ^= *state >> 7;
+            *state ^= *state << 17;
+            *state % bound
+        }
+
+        fn register_id(&mut self) -> RegisterId {
+            [RegisterId::A, RegisterId::B, RegisterId::C, RegisterId::D][self.next(4) as usize]
+        }
+
+        fn argument(&mut self) -> Argument {
+            match self.next(2) {
+                0 => Argument::Literal(self.next(2001) as Word - 1000),
+                _ => Argument::Reference(self.register_id()),
+            }
+        }
+
+        fn instruction(&mut self) -> Instruction {
+            match self.next(9) {
+                0 => Instruction::Cpy {
+                    from: self.argument(),
+                    into: self.register_id().into(),
+                },
+                1 => Instruction::Inc(self.register_id().into()),
+                2 => Instruction::Dec(self.register_id().into()),
+                3 => Instruction::Jnz {
+                    condition: self.argument(),
+                    jump_offset: self.argument(),
+                },
+                4 => Instruction::Tgl(self.argument()),
+                5 => Instruction::Out(self.argument()),
+                6 => Instruction::Add {
+                    from: self.register_id(),
+                    into: self.register_id(),
+                },
+                7 => Instruction::Mul {
+                    from: self.argument(),
+                    times: self.register_id(),
+                    into: self.register_id(),
+                    scratch: self.register_id(),
+                },
+                _ => Instruction::Nop,
+            }
+        }
+
+        fn program(&mut self) -> Assembunny {
+            let length = self.next(30);
+            Assembunny((0..length).map(|_| self.instruction()).collect())
+        }
+    }
+
+    #[test]
+    fn trait_display_fmt_test_round_trip() {
+        let mut programs = Programs(0x2016_1212);
+
+        for _ in 0..500 {
+            let assembunny = programs.program();
+
+            assert_eq!(Ok(assembunny.clone()), assembunny.to_string().parse());
+        }
+    }
+
+    #[test]
+    fn trait_display_fmt_test() {
+        let input = ["cpy 7 c", "inc d", "dec c", "jnz c -2"].join("\n");
+        let assembunny = input.parse::<Assembunny>().unwrap();
+
+        assert_eq!(input, assembunny.to_string());
+    }
+
+    #[test]
+    fn from_str_trait_from_str_test_err() {
+        let input = ["cpy 7 c", "inc d", "dec 5", "jnz c -2", "mov a b", "cpy 1"].join("\n");
+        let expected: Result<Assembunny, _> = Err(AssembunnyParseError::initialize(vec![
+            InvalidLine::new(3, "dec 5", InstructionParseError::initialize(5, "register")),
+            InvalidLine::new(
+                5,
+                "mov a b",
+                InstructionParseError::initialize(1, "instruction"),
+            ),
+            InvalidLine::new(6, "cpy 1", InstructionParseError::initialize(6, "space")),
+        ]));
+
+        assert_eq!(expected, input.parse());
+    }
+
+    #[test]
+    fn from_str_trait_from_str_test_err_remaining_input() {
+        let input = ["inc a garbage", "nopx", "nop", "cpy 1 a b"].join("\n");
+        let expected: Result<Assembunny, _> = Err(AssembunnyParseError::initialize(vec![
+            InvalidLine::new(
+                1,
+                "inc a garbage",
+                InstructionParseError::initialize(6, "end of instruction"),
+            ),
+            InvalidLine::new(
+                2,
+                "nopx",
+                InstructionParseError::initialize(1, "instruction"),
+            ),
+            InvalidLine::new(
+                4,
+                "cpy 1 a b",
+                InstructionParseError::initialize(8, "end of instruction"),
+            ),
+        ]));
+
+        assert_eq!(expected, input.parse());
+    }
+
+    #[test]
+    fn from_str_trait_from_str_test_report() {
+        let mut input = ["inc a"; 10];
+        input[1] = "jnz a ?";
+        input[9] = "tgl";
+        let expected = [
+            "Could not parse assembunny, 2 invalid lines.",
+            "error: expected register or number",
+            " --> 2:7",
+            "  |",
+            "2 | jnz a ?",
+            "  |       ^",
+            "",
+            "error: expected space",
+            "  --> 10:4",
+            "   |",
+            "10 | tgl",
+            "   |    ^",
+        ]
+        .join("\n");
+
+        let error = input.join("\n").parse::<Assembunny>().unwrap_err();
+
+        assert_eq!(expected, error.to_string());
+    }
+}
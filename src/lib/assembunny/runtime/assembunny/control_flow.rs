@@ -0,0 +1,374 @@
+use std::{collections::BTreeSet, fmt::Display, ops::Range};
+
+use super::{Argument, Assembunny, Instruction, Word};
+
+/// Where the control flow may continue after a [`BasicBlock`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Successor {
+    /// The block at this index of [`ControlFlowGraph::blocks`].
+    Block(usize),
+    /// Leaves the program, which halts it.
+    Exit,
+    /// A jump by a register offset, which can only be resolved at runtime.
+    Dynamic,
+}
+
+/// A run of instructions that is only entered at its first and only left after its last
+/// instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BasicBlock {
+    pub instructions: Range<usize>,
+    pub successors: Vec<Successor>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ControlFlowGraph {
+    blocks: Vec<BasicBlock>,
+}
+
+impl Assembunny {
+    /// Builds the control flow graph of the program as it is. Instructions changed by `tgl` at
+    /// runtime are not taken into account.
+    pub fn control_flow_graph(&self) -> ControlFlowGraph {
+        let leaders = self
+            .iter()
+            .enumerate()
+            .filter_map(|(ip, instruction)| match *instruction {
+                Instruction::Jnz {
+                    condition,
+                    jump_offset,
+                } => {
+                    let target = match (condition, jump_offset) {
+                        (Argument::Literal(0), _) | (_, Argument::Reference(_)) => None,
+                        (_, Argument::Literal(offset)) => {
+                            match jump_target(ip, offset, self.len()) {
+                                Target::Instruction(target) => Some(target),
+                                Target::End | Target::OutOfRange(_) => None,
+                            }
+                        }
+                    };
+                    Some([Some(ip + 1), target])
+                }
+                _ => None,
+            })
+            .flatten()
+            .flatten()
+            .chain([0])
+            .filter(|leader| *leader < self.len())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        let block_of = |ip: usize| match leaders.binary_search(&ip) {
+            Ok(index) => Successor::Block(index),
+            Err(_) => unreachable!("Every jump target starts a block."),
+        };
+        let target_of = |ip: usize, offset| match jump_target(ip, offset, self.len()) {
+            Target::Instruction(target) => block_of(target),
+            Target::End | Target::OutOfRange(_) => Successor::Exit,
+        };
+        let blocks = leaders
+            .iter()
+            .enumerate()
+            .map(|(index, &start)| {
+                let end = leaders.get(index + 1).copied().unwrap_or(self.len());
+                let last = end - 1;
+                let next = if end < self.len() {
+                    Successor::Block(index + 1)
+                } else {
+                    Successor::Exit
+                };
+                let successors = match self[last] {
+                    Instruction::Jnz {
+                        condition: Argument::Literal(0),
+                        ..
+                    } => vec![next],
+                    Instruction::Jnz {
+                        condition: Argument::Literal(_),
+                        jump_offset: Argument::Literal(offset),
+                    } => vec![target_of(last, offset)],
+                    Instruction::Jnz {
+                        condition: Argument::Reference(_),
+                        jump_offset: Argument::Literal(offset),
+                    } => {
+                        let jump = target_of(last, offset);
+                        if jump == next {
+                            vec![next]
+                        } else {
+                            vec![jump, next]
+                        }
+                    }
+                    Instruction::Jnz {
+                        condition: Argument::Literal(_),
+                        jump_offset: Argument::Reference(_),
+                    } => vec![Successor::Dynamic],
+                    Instruction::Jnz {
+                        condition: Argument::Reference(_),
+                        jump_offset: Argument::Reference(_),
+                    } => vec![Successor::Dynamic, next],
+                    _ => vec![next],
+                };
+                BasicBlock {
+                    instructions: start..end,
+                    successors,
+                }
+            })
+            .collect();
+
+        ControlFlowGraph { blocks }
+    }
+}
+
+impl ControlFlowGraph {
+    pub fn blocks(&self) -> &[BasicBlock] {
+        &self.blocks
+    }
+
+    /// Marks every block that can be reached from the start of the program. Returns `None` if a
+    /// reachable block has a [`Successor::Dynamic`], since it could lead anywhere.
+    pub fn reachable_blocks(&self) -> Option<Vec<bool>> {
+        let mut reachable = vec![false; self.blocks.len()];
+        let mut pending = if self.blocks.is_empty() {
+            Vec::new()
+        } else {
+            vec![0]
+        };
+
+        while let Some(index) = pending.pop() {
+            if reachable[index] {
+                continue;
+            }
+            reachable[index] = true;
+            for successor in &self.blocks[index].successors {
+                match successor {
+                    Successor::Block(next) => pending.push(*next),
+                    Successor::Exit => {}
+                    Successor::Dynamic => return None,
+                }
+            }
+        }
+
+        Some(reachable)
+    }
+}
+
+enum Target {
+    Instruction(usize),
+    End,
+    OutOfRange(isize),
+}
+
+fn jump_target(ip: usize, offset: Word, len: usize) -> Target {
+    let target = (ip as isize).saturating_add(offset as isize);
+    match usize::try_from(target) {
+        Ok(target) if target < len => Target::Instruction(target),
+        Ok(target) if target == len => Target::End,
+        _ => Target::OutOfRange(target),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LintWarning {
+    Unreachable(Range<usize>),
+    /// A jump that leaves the program somewhere else than directly after its last instruction.
+    JumpOutOfRange {
+        ip: usize,
+        target: isize,
+    },
+    DynamicJump {
+        ip: usize,
+    },
+    /// A `tgl`, which may change the control flow at runtime.
+    SelfModifying {
+        ip: usize,
+    },
+}
+
+impl LintWarning {
+    fn ip(&self) -> usize {
+        match self {
+            Self::Unreachable(instructions) => instructions.start,
+            Self::JumpOutOfRange { ip, .. }
+            | Self::DynamicJump { ip }
+            | Self::SelfModifying { ip } => *ip,
+        }
+    }
+}
+
+impl Display for LintWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unreachable(instructions) if instructions.len() == 1 => {
+                write!(f, "{}: instruction is unreachable", instructions.start)
+            }
+            Self::Unreachable(instructions) => write!(
+                f,
+                "{}..{}: instructions are unreachable",
+                instructions.start, instructions.end
+            ),
+            Self::JumpOutOfRange { ip, target } => {
+                write!(f, "{ip}: jump to {target} leaves the program")
+            }
+            Self::DynamicJump { ip } => {
+                write!(f, "{ip}: jump offset is only known at runtime")
+            }
+            Self::SelfModifying { ip } => {
+                write!(f, "{ip}: tgl may change the control flow at runtime")
+            }
+        }
+    }
+}
+
+/// Checks a program for suspicious control flow, ordered by instruction pointer. Unreachable
+/// instructions are only reported if neither a dynamic jump nor a `tgl` is reachable.
+pub fn lint(assembunny: &Assembunny) -> Vec<LintWarning> {
+    let mut warnings = Vec::new();
+    for (ip, instruction) in assembunny.iter().enumerate() {
+        match *instruction {
+            Instruction::Jnz {
+                condition,
+                jump_offset: Argument::Literal(offset),
+            } if condition != Argument::Literal(0) => {
+                if let Target::OutOfRange(target) = jump_target(ip, offset, assembunny.len()) {
+                    warnings.push(LintWarning::JumpOutOfRange { ip, target });
+                }
+            }
+            Instruction::Jnz {
+                jump_offset: Argument::Reference(_),
+                ..
+            } => warnings.push(LintWarning::DynamicJump { ip }),
+            Instruction::Tgl(_) => warnings.push(LintWarning::SelfModifying { ip }),
+            _ => {}
+        }
+    }
+
+    let control_flow_graph = assembunny.control_flow_graph();
+    if let Some(reachable) = control_flow_graph.reachable_blocks() {
+        let blocks = control_flow_graph.blocks();
+        let is_self_modifying = blocks.iter().zip(&reachable).any(|(block, reachable)| {
+            *reachable
+                && assembunny[block.instructions.clone()]
+                    .iter()
+                    .any(|instruction| matches!(instruction, Instruction::Tgl(_)))
+        });
+        if !is_self_modifying {
+            let mut unreachable: Vec<Range<usize>> = Vec::new();
+            for (block, _) in blocks.iter().zip(reachable).filter(|(_, is)| !is) {
+                match unreachable.last_mut() {
+                    Some(last) if last.end == block.instructions.start => {
+                        last.end = block.instructions.end;
+                    }
+                    _ => unreachable.push(block.instructions.clone()),
+                }
+            }
+            warnings.extend(unreachable.into_iter().map(LintWarning::Unreachable));
+        }
+    }
+
+    warnings.sort_by_key(LintWarning::ip);
+    warnings
+}
+
+#[cfg(test)]
+mod control_flow_tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::assembunny::assembunny;
+
+    #[test]
+    fn control_flow_graph_test() {
+        let assembunny = assembunny(&[
+            "cpy 2 a", "jnz a 2", "jnz 1 5", "dec a", "inc b", "jnz a -2", "jnz 0 -5", "jnz 1 a",
+        ]);
+        let expected = vec![
+            BasicBlock {
+                instructions: 0..2,
+                successors: vec![Successor::Block(2), Successor::Block(1)],
+            },
+            BasicBlock {
+                instructions: 2..3,
+                successors: vec![Successor::Block(4)],
+            },
+            BasicBlock {
+                instructions: 3..6,
+                successors: vec![Successor::Block(2), Successor::Block(3)],
+            },
+            BasicBlock {
+                instructions: 6..7,
+                successors: vec![Successor::Block(4)],
+            },
+            BasicBlock {
+                instructions: 7..8,
+                successors: vec![Successor::Dynamic],
+            },
+        ];
+
+        assert_eq!(expected, assembunny.control_flow_graph().blocks());
+    }
+
+    #[test]
+    fn control_flow_graph_test_empty() {
+        let control_flow_graph = Assembunny(Vec::new()).control_flow_graph();
+
+        assert!(control_flow_graph.blocks().is_empty());
+        assert_eq!(Some(Vec::new()), control_flow_graph.reachable_blocks());
+    }
+
+    #[rstest]
+    #[case(&["cpy 3 a", "dec a", "jnz a -1"], Some(vec![true, true]))]
+    #[case(&["jnz 1 2", "inc a", "dec a"], Some(vec![true, false, true]))]
+    #[case(&["jnz 1 3", "inc a", "jnz 1 -1", "dec a"], Some(vec![true, false, true]))]
+    #[case(&["jnz 1 a", "inc a"], None)]
+    fn reachable_blocks_test(#[case] program: &[&str], #[case] expected: Option<Vec<bool>>) {
+        let control_flow_graph = assembunny(program).control_flow_graph();
+
+        assert_eq!(expected, control_flow_graph.reachable_blocks());
+    }
+
+    #[rstest]
+    #[case(&["cpy 3 a", "dec a", "jnz a -1", "jnz 1 1"], vec![])]
+    #[case(&["jnz 1 3", "inc a", "dec a", "inc b"], vec![LintWarning::Unreachable(1..3)])]
+    #[case(&["jnz 1 3", "inc a", "jnz 1 -1", "dec a"], vec![LintWarning::Unreachable(1..3)])]
+    #[case(
+        &["jnz a 4", "jnz 1 -2", "inc a"],
+        vec![
+            LintWarning::JumpOutOfRange { ip: 0, target: 4 },
+            LintWarning::JumpOutOfRange { ip: 1, target: -1 },
+            LintWarning::Unreachable(2..3),
+        ]
+    )]
+    #[case(&["jnz 0 -5", "inc a"], vec![])]
+    #[case(
+        &["jnz 1 b", "jnz 1 2", "inc a"],
+        vec![LintWarning::DynamicJump { ip: 0 }]
+    )]
+    #[case(
+        &["tgl a", "jnz 1 2", "inc a"],
+        vec![LintWarning::SelfModifying { ip: 0 }]
+    )]
+    #[case(
+        &["jnz 1 2", "tgl a", "jnz 1 2", "inc a"],
+        vec![LintWarning::SelfModifying { ip: 1 }, LintWarning::Unreachable(1..2), LintWarning::Unreachable(3..4)]
+    )]
+    fn lint_test(#[case] program: &[&str], #[case] expected: Vec<LintWarning>) {
+        assert_eq!(expected, lint(&assembunny(program)));
+    }
+
+    #[rstest]
+    #[case(LintWarning::Unreachable(3..4), "3: instruction is unreachable")]
+    #[case(LintWarning::Unreachable(3..6), "3..6: instructions are unreachable")]
+    #[case(
+        LintWarning::JumpOutOfRange { ip: 2, target: -1 },
+        "2: jump to -1 leaves the program"
+    )]
+    #[case(LintWarning::DynamicJump { ip: 1 }, "1: jump offset is only known at runtime")]
+    #[case(
+        LintWarning::SelfModifying { ip: 0 },
+        "0: tgl may change the control flow at runtime"
+    )]
+    fn trait_display_fmt_test(#[case] warning: LintWarning, #[case] expected: &str) {
+        assert_eq!(expected, warning.to_string());
+    }
+}
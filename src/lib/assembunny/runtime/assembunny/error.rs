@@ -0,0 +1,86 @@
+use std::fmt::Display;
+
+use snafu::prelude::*;
+
+use super::instruction::InstructionParseError;
+
+/// Every line of the source that could not be parsed. Its display is a report that shows each
+/// line with a caret under the failing token.
+#[derive(Debug, Clone, PartialEq, Snafu)]
+#[snafu(
+    display(
+        "Could not parse assembunny, {} invalid {}.\n{}",
+        invalid_lines.len(),
+        if invalid_lines.len() == 1 { "line" } else { "lines" },
+        invalid_lines.iter().map(InvalidLine::to_string).collect::<Vec<_>>().join("\n\n")
+    ),
+    visibility(pub)
+)]
+pub struct AssembunnyParseError {
+    invalid_lines: Vec<InvalidLine>,
+}
+
+impl AssembunnyParseError {
+    pub fn invalid_lines(&self) -> &[InvalidLine] {
+        &self.invalid_lines
+    }
+
+    #[cfg(test)]
+    pub fn initialize(invalid_lines: Vec<InvalidLine>) -> Self {
+        Self { invalid_lines }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidLine {
+    line_number: usize,
+    text: String,
+    source: InstructionParseError,
+}
+
+impl InvalidLine {
+    pub fn new(line_number: usize, text: &str, source: InstructionParseError) -> Self {
+        Self {
+            line_number,
+            text: text.to_string(),
+            source,
+        }
+    }
+
+    /// The number of the line in the source, starting at 1.
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    pub fn column(&self) -> usize {
+        self.source.column()
+    }
+
+    pub fn source(&self) -> &InstructionParseError {
+        &self.source
+    }
+}
+
+impl Display for InvalidLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let gutter = " ".repeat(self.line_number.to_string().len());
+        let token_length = self
+            .text
+            .chars()
+            .skip(self.column() - 1)
+            .take_while(|char| !char.is_whitespace())
+            .count()
+            .max(1);
+
+        writeln!(f, "error: expected {}", self.source.expected())?;
+        writeln!(f, "{gutter}--> {}:{}", self.line_number, self.column())?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line_number, self.text)?;
+        write!(
+            f,
+            "{gutter} | {}{}",
+            " ".repeat(self.column() - 1),
+            "^".repeat(token_length)
+        )
+    }
+}
@@ -0,0 +1,232 @@
+mod argument;
+mod parsing;
+mod register_id;
+
+use std::{fmt::Display, str::FromStr};
+
+use nom::{combinator::all_consuming, Finish};
+
+pub use self::{argument::Argument, parsing::InstructionParseError, register_id::RegisterId};
+
+/// Large enough for every [`WordSize`](crate::assembunny::WordSize).
+pub type Word = i64;
+
+/// Arguments that have to be registers are still stored as [`Argument`], because `tgl` can
+/// turn valid instructions into invalid ones, e.g. `jnz 1 2` into `cpy 1 2`. Those are skipped
+/// when executed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Instruction {
+    Cpy {
+        from: Argument,
+        into: Argument,
+    },
+    Inc(Argument),
+    Dec(Argument),
+    Jnz {
+        condition: Argument,
+        jump_offset: Argument,
+    },
+    Tgl(Argument),
+    Out(Argument),
+    /// Synthetic, see [`Assembunny::optimized`](super::Assembunny::optimized): replaces the
+    /// `jnz from -2` closing a loop that adds `from` to `into`. If `from` is positive, it is
+    /// added to `into` and cleared, otherwise this acts like the replaced `jnz`.
+    Add {
+        from: RegisterId,
+        into: RegisterId,
+    },
+    /// Synthetic: replaces the `jnz times -5` closing a loop that multiplies. If `from` and
+    /// `times` are positive, `from * times` is added to `into` and `times` and `scratch` are
+    /// cleared, otherwise this acts like the replaced `jnz`.
+    Mul {
+        from: Argument,
+        times: RegisterId,
+        into: RegisterId,
+        scratch: RegisterId,
+    },
+    /// Synthetic: does nothing.
+    Nop,
+}
+
+impl Instruction {
+    /// Every register the instruction reads or writes. `tgl` keeps the arguments, so toggled
+    /// instructions use the same registers.
+    pub fn register_ids(self) -> impl Iterator<Item = RegisterId> {
+        let arguments = match self {
+            Self::Cpy { from, into } => vec![from, into],
+            Self::Inc(argument)
+            | Self::Dec(argument)
+            | Self::Tgl(argument)
+            | Self::Out(argument) => {
+                vec![argument]
+            }
+            Self::Jnz {
+                condition,
+                jump_offset,
+            } => vec![condition, jump_offset],
+            Self::Add { from, into } => vec![from.into(), into.into()],
+            Self::Mul {
+                from,
+                times,
+                into,
+                scratch,
+            } => vec![from, times.into(), into.into(), scratch.into()],
+            Self::Nop => Vec::new(),
+        };
+        arguments.into_iter().filter_map(|argument| match argument {
+            Argument::Reference(register_id) => Some(register_id),
+            Argument::Literal(_) => None,
+        })
+    }
+
+    /// The jump offset of the `jnz` replaced by [`Self::Add`].
+    pub(crate) const ADD_JUMP_OFFSET: Word = -2;
+    /// The jump offset of the `jnz` replaced by [`Self::Mul`].
+    pub(crate) const MUL_JUMP_OFFSET: Word = -5;
+
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Inc(argument) => Self::Dec(argument),
+            Self::Dec(argument) | Self::Tgl(argument) | Self::Out(argument) => Self::Inc(argument),
+            Self::Jnz {
+                condition,
+                jump_offset,
+            } => Self::Cpy {
+                from: condition,
+                into: jump_offset,
+            },
+            Self::Cpy { from, into } => Self::Jnz {
+                condition: from,
+                jump_offset: into,
+            },
+            Self::Add { .. } | Self::Mul { .. } | Self::Nop => self,
+        }
+    }
+}
+
+/// Prints the instruction the way it is parsed. Invalid forms created by `tgl`, e.g. `cpy 1 2`,
+/// are printed as well, but cannot be parsed again.
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cpy { from, into } => write!(f, "cpy {from} {into}"),
+            Self::Inc(register) => write!(f, "inc {register}"),
+            Self::Dec(register) => write!(f, "dec {register}"),
+            Self::Jnz {
+                condition,
+                jump_offset,
+            } => write!(f, "jnz {condition} {jump_offset}"),
+            Self::Tgl(offset) => write!(f, "tgl {offset}"),
+            Self::Out(value) => write!(f, "out {value}"),
+            Self::Add { from, into } => write!(f, "add {from} {into}"),
+            Self::Mul {
+                from,
+                times,
+                into,
+                scratch,
+            } => write!(f, "mul {from} {times} {into} {scratch}"),
+            Self::Nop => write!(f, "nop"),
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = InstructionParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        all_consuming(parsing::parse_instruction)(string)
+            .finish()
+            .map(|(_, output)| output)
+            .map_err(|error| InstructionParseError::with_parse_context(string, error))
+    }
+}
+
+#[cfg(test)]
+mod instruction_tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Instruction::Inc(RegisterId::A.into()), Instruction::Dec(RegisterId::A.into()))]
+    #[case(Instruction::Dec(RegisterId::A.into()), Instruction::Inc(RegisterId::A.into()))]
+    #[case(Instruction::Tgl(RegisterId::A.into()), Instruction::Inc(RegisterId::A.into()))]
+    #[case(Instruction::Out(5.into()), Instruction::Inc(5.into()))]
+    #[case(
+        Instruction::Jnz { condition: 1.into(), jump_offset: RegisterId::C.into() },
+        Instruction::Cpy { from: 1.into(), into: RegisterId::C.into() }
+    )]
+    #[case(
+        Instruction::Cpy { from: RegisterId::A.into(), into: RegisterId::B.into() },
+        Instruction::Jnz { condition: RegisterId::A.into(), jump_offset: RegisterId::B.into() }
+    )]
+    fn toggled_test(#[case] instruction: Instruction, #[case] expected: Instruction) {
+        assert_eq!(expected, instruction.toggled());
+    }
+
+    #[rstest]
+    #[case("cpy -7 d", &['d'])]
+    #[case("jnz a b", &['a', 'b'])]
+    #[case("out 3", &[])]
+    #[case("mul e f g h", &['e', 'f', 'g', 'h'])]
+    #[case("nop", &[])]
+    fn register_ids_test(#[case] input: &str, #[case] expected: &[char]) {
+        let instruction = input.parse::<Instruction>().unwrap();
+
+        assert_eq!(
+            expected
+                .iter()
+                .map(|name| RegisterId::from(*name))
+                .collect::<Vec<_>>(),
+            instruction.register_ids().collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest]
+    #[case("cpy -7 d")]
+    #[case("cpy b a")]
+    #[case("inc c")]
+    #[case("dec a")]
+    #[case("jnz 0 -12")]
+    #[case("jnz a b")]
+    #[case("tgl 3")]
+    #[case("out d")]
+    #[case("add b a")]
+    #[case("mul -3 d a c")]
+    #[case("nop")]
+    fn trait_display_fmt_test_round_trip(#[case] input: &str) {
+        let instruction = input.parse::<Instruction>().unwrap();
+
+        assert_eq!(input, instruction.to_string());
+    }
+
+    #[test]
+    fn trait_display_fmt_test_invalid() {
+        let instruction = Instruction::Cpy {
+            from: 1.into(),
+            into: 2.into(),
+        };
+
+        assert_eq!("cpy 1 2", instruction.to_string());
+    }
+
+    #[rstest]
+    #[case("inc 5", 5, "register")]
+    #[case("cpy X a", 5, "register or number")]
+    #[case("jnz 1", 6, "space")]
+    #[case("mul 2 a b 3", 11, "register")]
+    #[case("hlt", 1, "instruction")]
+    #[case("nopx", 1, "instruction")]
+    #[case("inc a garbage", 6, "end of instruction")]
+    #[case("cpy 1 a b", 8, "end of instruction")]
+    #[case("", 1, "instruction")]
+    fn trait_from_str_from_str_test_err(
+        #[case] input: &str,
+        #[case] column: usize,
+        #[case] expected: &str,
+    ) {
+        let error = input.parse::<Instruction>().unwrap_err();
+
+        assert_eq!(InstructionParseError::initialize(column, expected), error);
+    }
+}
@@ -0,0 +1,30 @@
+use std::fmt::Display;
+
+use super::{register_id::RegisterId, Word};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Argument {
+    Literal(Word),
+    Reference(RegisterId),
+}
+
+impl From<Word> for Argument {
+    fn from(value: Word) -> Self {
+        Self::Literal(value)
+    }
+}
+
+impl From<RegisterId> for Argument {
+    fn from(register_id: RegisterId) -> Self {
+        Self::Reference(register_id)
+    }
+}
+
+impl Display for Argument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Literal(value) => write!(f, "{value}"),
+            Self::Reference(register_id) => write!(f, "{register_id}"),
+        }
+    }
+}
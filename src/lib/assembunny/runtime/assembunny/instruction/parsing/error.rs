@@ -0,0 +1,63 @@
+use nom::error::{ErrorKind, VerboseError, VerboseErrorKind};
+use snafu::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Snafu)]
+#[snafu(display("Could not parse instruction: expected {expected} at column {column}."))]
+pub struct InstructionParseError {
+    column: usize,
+    expected: String,
+}
+
+impl InstructionParseError {
+    /// Locates the error at the innermost failing parser and describes it by the outermost
+    /// context that failed at the same position.
+    pub fn with_parse_context(input: &str, error: VerboseError<&str>) -> Self {
+        let Some((remaining_input, kind)) = error.errors.first() else {
+            return Self {
+                column: 1,
+                expected: "instruction".to_string(),
+            };
+        };
+        let consumed_input = &input[..input.len() - remaining_input.len()];
+        let context = error
+            .errors
+            .iter()
+            .rev()
+            .filter(|(input, _)| input.len() == remaining_input.len())
+            .filter_map(|(_, kind)| match kind {
+                VerboseErrorKind::Context(context) => Some(*context),
+                _ => None,
+            })
+            .next();
+        let expected = match (context, kind) {
+            (Some(context), _) => context.to_string(),
+            (None, VerboseErrorKind::Char(' ')) => "space".to_string(),
+            (None, VerboseErrorKind::Char(char)) => format!("`{char}`"),
+            (None, VerboseErrorKind::Context(context)) => context.to_string(),
+            (None, VerboseErrorKind::Nom(ErrorKind::Eof)) => "end of instruction".to_string(),
+            (None, VerboseErrorKind::Nom(kind)) => kind.description().to_lowercase(),
+        };
+
+        Self {
+            column: consumed_input.chars().count() + 1,
+            expected,
+        }
+    }
+
+    /// The column of the failing token, starting at 1.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    #[cfg(test)]
+    pub fn initialize(column: usize, expected: &str) -> Self {
+        Self {
+            column,
+            expected: expected.to_string(),
+        }
+    }
+}
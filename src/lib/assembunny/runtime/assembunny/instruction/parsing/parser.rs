@@ -0,0 +1,334 @@
+use std::{num::ParseIntError, str::FromStr};
+
+use nom::{
+    branch::alt,
+    character::complete::{alphanumeric1, anychar, char, digit1},
+    combinator::{cut, map, map_opt, map_res, opt, recognize, verify},
+    error::{context, ContextError, FromExternalError, ParseError},
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+use crate::assembunny::{Argument, Instruction, RegisterId, Word};
+
+pub fn parse_instruction<'a, E>(input: &'a str) -> IResult<&'a str, Instruction, E>
+where
+    E: ParseError<&'a str> + ContextError<&'a str> + FromExternalError<&'a str, ParseIntError>,
+{
+    context(
+        "instruction",
+        alt((
+            parse_instruction_cpy,
+            parse_instruction_inc,
+            parse_instruction_dec,
+            parse_instruction_jnz,
+            parse_instruction_tgl,
+            parse_instruction_out,
+            parse_instruction_add,
+            parse_instruction_mul,
+            parse_instruction_nop,
+        )),
+    )(input)
+}
+
+fn parse_instruction_cpy<'a, E>(input: &'a str) -> IResult<&'a str, Instruction, E>
+where
+    E: ParseError<&'a str> + ContextError<&'a str> + FromExternalError<&'a str, ParseIntError>,
+{
+    map(
+        preceded(
+            mnemonic("cpy"),
+            cut(tuple((
+                char(' '),
+                parse_argument,
+                char(' '),
+                parse_argument_reference,
+            ))),
+        ),
+        |(_, from, _, into)| Instruction::Cpy { from, into },
+    )(input)
+}
+
+fn parse_instruction_inc<'a, E>(input: &'a str) -> IResult<&'a str, Instruction, E>
+where
+    E: ParseError<&'a str> + ContextError<&'a str> + FromExternalError<&'a str, ParseIntError>,
+{
+    map(
+        preceded(
+            mnemonic("inc"),
+            cut(tuple((char(' '), parse_argument_reference))),
+        ),
+        |(_, register)| Instruction::Inc(register),
+    )(input)
+}
+
+fn parse_instruction_dec<'a, E>(input: &'a str) -> IResult<&'a str, Instruction, E>
+where
+    E: ParseError<&'a str> + ContextError<&'a str> + FromExternalError<&'a str, ParseIntError>,
+{
+    map(
+        preceded(
+            mnemonic("dec"),
+            cut(tuple((char(' '), parse_argument_reference))),
+        ),
+        |(_, register)| Instruction::Dec(register),
+    )(input)
+}
+
+fn parse_instruction_jnz<'a, E>(input: &'a str) -> IResult<&'a str, Instruction, E>
+where
+    E: ParseError<&'a str> + ContextError<&'a str> + FromExternalError<&'a str, ParseIntError>,
+{
+    map(
+        preceded(
+            mnemonic("jnz"),
+            cut(tuple((
+                char(' '),
+                parse_argument,
+                char(' '),
+                parse_argument,
+            ))),
+        ),
+        |(_, condition, _, jump_offset)| Instruction::Jnz {
+            condition,
+            jump_offset,
+        },
+    )(input)
+}
+
+fn parse_instruction_tgl<'a, E>(input: &'a str) -> IResult<&'a str, Instruction, E>
+where
+    E: ParseError<&'a str> + ContextError<&'a str> + FromExternalError<&'a str, ParseIntError>,
+{
+    map(
+        preceded(mnemonic("tgl"), cut(tuple((char(' '), parse_argument)))),
+        |(_, offset)| Instruction::Tgl(offset),
+    )(input)
+}
+
+fn parse_instruction_out<'a, E>(input: &'a str) -> IResult<&'a str, Instruction, E>
+where
+    E: ParseError<&'a str> + ContextError<&'a str> + FromExternalError<&'a str, ParseIntError>,
+{
+    map(
+        preceded(mnemonic("out"), cut(tuple((char(' '), parse_argument)))),
+        |(_, value)| Instruction::Out(value),
+    )(input)
+}
+
+fn parse_instruction_add<'a, E>(input: &'a str) -> IResult<&'a str, Instruction, E>
+where
+    E: ParseError<&'a str> + ContextError<&'a str>,
+{
+    map(
+        preceded(
+            mnemonic("add"),
+            cut(tuple((
+                char(' '),
+                parse_register_id,
+                char(' '),
+                parse_register_id,
+            ))),
+        ),
+        |(_, from, _, into)| Instruction::Add { from, into },
+    )(input)
+}
+
+fn parse_instruction_mul<'a, E>(input: &'a str) -> IResult<&'a str, Instruction, E>
+where
+    E: ParseError<&'a str> + ContextError<&'a str> + FromExternalError<&'a str, ParseIntError>,
+{
+    map(
+        preceded(
+            mnemonic("mul"),
+            cut(tuple((
+                char(' '),
+                parse_argument,
+                char(' '),
+                parse_register_id,
+                char(' '),
+                parse_register_id,
+                char(' '),
+                parse_register_id,
+            ))),
+        ),
+        |(_, from, _, times, _, into, _, scratch)| Instruction::Mul {
+            from,
+            times,
+            into,
+            scratch,
+        },
+    )(input)
+}
+
+fn parse_instruction_nop<'a, E>(input: &'a str) -> IResult<&'a str, Instruction, E>
+where
+    E: ParseError<&'a str> + ContextError<&'a str>,
+{
+    map(mnemonic("nop"), |_| Instruction::Nop)(input)
+}
+
+/// Matches `name` only as a whole word, so that e.g. `nopx` is not read as `nop`. A mismatch
+/// fails at the start of the word.
+fn mnemonic<'a, E>(name: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str, E>
+where
+    E: ParseError<&'a str>,
+{
+    verify(alphanumeric1, move |word: &str| word == name)
+}
+
+fn parse_argument<'a, E>(input: &'a str) -> IResult<&'a str, Argument, E>
+where
+    E: ParseError<&'a str> + ContextError<&'a str> + FromExternalError<&'a str, ParseIntError>,
+{
+    context(
+        "register or number",
+        alt((parse_argument_literal, parse_argument_reference)),
+    )(input)
+}
+
+fn parse_argument_literal<'a, E>(input: &'a str) -> IResult<&'a str, Argument, E>
+where
+    E: ParseError<&'a str> + ContextError<&'a str> + FromExternalError<&'a str, ParseIntError>,
+{
+    map(parse_word, Argument::Literal)(input)
+}
+
+fn parse_argument_reference<'a, E>(input: &'a str) -> IResult<&'a str, Argument, E>
+where
+    E: ParseError<&'a str> + ContextError<&'a str>,
+{
+    map(parse_register_id, Argument::Reference)(input)
+}
+
+fn parse_word<'a, E>(input: &'a str) -> IResult<&'a str, Word, E>
+where
+    E: ParseError<&'a str> + ContextError<&'a str> + FromExternalError<&'a str, ParseIntError>,
+{
+    map_res(
+        recognize(tuple((opt(char('-')), digit1))),
+        FromStr::from_str,
+    )(input)
+}
+
+fn parse_register_id<'a, E>(input: &'a str) -> IResult<&'a str, RegisterId, E>
+where
+    E: ParseError<&'a str> + ContextError<&'a str>,
+{
+    context("register", map_opt(anychar, RegisterId::new))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        error::{convert_error, VerboseError},
+        Finish, Parser,
+    };
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn unwrap_verbose<'a, O>(
+        mut parser: impl Parser<&'a str, O, VerboseError<&'a str>>,
+        input: &'a str,
+    ) -> (&'a str, O) {
+        parser
+            .parse(input)
+            .finish()
+            .unwrap_or_else(|error| panic!("{}", convert_error(input, error)))
+    }
+
+    #[rstest]
+    #[case(Instruction::Cpy { from: Argument::Literal(1), into: Argument::Reference(RegisterId::A) }, "cpy 1 a")]
+    #[case(Instruction::Inc(Argument::Reference(RegisterId::D)), "inc d")]
+    #[case(Instruction::Dec(Argument::Reference(RegisterId::C)), "dec c")]
+    #[case(Instruction::Jnz { condition: Argument::Reference(RegisterId::B), jump_offset: Argument::Literal(-2) }, "jnz b -2")]
+    #[case(Instruction::Jnz { condition: Argument::Literal(1), jump_offset: Argument::Reference(RegisterId::C) }, "jnz 1 c")]
+    #[case(Instruction::Tgl(Argument::Reference(RegisterId::C)), "tgl c")]
+    #[case(Instruction::Out(Argument::Reference(RegisterId::B)), "out b")]
+    #[case(Instruction::Out(Argument::Literal(0)), "out 0")]
+    #[case(Instruction::Add { from: RegisterId::B, into: RegisterId::A }, "add b a")]
+    #[case(Instruction::Mul { from: Argument::Literal(7), times: RegisterId::D, into: RegisterId::A, scratch: RegisterId::C }, "mul 7 d a c")]
+    #[case(Instruction::Nop, "nop")]
+    fn parse_instruction_test(#[case] expected: Instruction, #[case] input: &str) {
+        let (remaining_input, actual) = unwrap_verbose(parse_instruction, input);
+
+        assert_eq!(expected, actual);
+        assert_eq!(remaining_input, "");
+    }
+
+    #[rstest]
+    #[case("nop", "")]
+    #[case("nop a", " a")]
+    #[case("cpy 1 a b", " b")]
+    fn parse_instruction_test_remaining_input(#[case] input: &str, #[case] expected: &str) {
+        let (remaining_input, _) = unwrap_verbose(parse_instruction, input);
+
+        assert_eq!(expected, remaining_input);
+    }
+
+    #[rstest]
+    #[case(Argument::Reference(RegisterId::A), "a")]
+    #[case(Argument::Reference(RegisterId::B), "b")]
+    #[case(Argument::Reference(RegisterId::C), "c")]
+    #[case(Argument::Reference(RegisterId::D), "d")]
+    #[case(Argument::Literal(5), "5")]
+    #[case(Argument::Literal(-5), "-5")]
+    fn parse_argument_test(#[case] expected: Argument, #[case] input: &str) {
+        let (remaining_input, actual) = unwrap_verbose(parse_argument, input);
+
+        assert_eq!(expected, actual);
+        assert_eq!(remaining_input, "");
+    }
+
+    #[rstest]
+    #[case(0, "0")]
+    #[case(5, "5")]
+    #[case(10, "10")]
+    #[case(-5, "-5")]
+    #[case(-10, "-10")]
+    fn parse_word_test(#[case] expected: Word, #[case] input: &str) {
+        let (remaining_input, actual) = unwrap_verbose(parse_word, input);
+
+        assert_eq!(expected, actual);
+        assert_eq!(remaining_input, "");
+    }
+
+    #[rstest]
+    #[case(RegisterId::A, "a")]
+    #[case(RegisterId::B, "b")]
+    #[case(RegisterId::C, "c")]
+    #[case(RegisterId::D, "d")]
+    #[case(RegisterId::new('e').unwrap(), "e")]
+    #[case(RegisterId::new('z').unwrap(), "z")]
+    fn parse_register_id_test_valid_register_id(#[case] expected: RegisterId, #[case] input: &str) {
+        let (remaining_input, actual) = unwrap_verbose(parse_register_id, input);
+
+        assert_eq!(expected, actual);
+        assert_eq!(remaining_input, "");
+    }
+
+    #[rstest]
+    #[case("cpy 1 2")]
+    #[case("inc 5")]
+    #[case("dec -1")]
+    #[case("mul a b")]
+    #[case("add 1 a")]
+    #[case("nopx")]
+    #[case("nop1")]
+    #[case("incx a")]
+    #[should_panic]
+    fn parse_instruction_test_invalid(#[case] input: &str) {
+        parse_instruction::<VerboseError<&str>>(input).unwrap();
+    }
+
+    #[rstest]
+    #[case("A")]
+    #[case("1")]
+    #[case("")]
+    #[should_panic]
+    fn parse_register_id_test_invalid_register_id(#[case] input: &str) {
+        parse_register_id::<VerboseError<&str>>(input).unwrap();
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt::Display;
+
+/// A register named by a lowercase letter, which is also its position in [`Registers`]. Assembunny
+/// only uses `a` to `d`, other register machines may use any letter.
+///
+/// [`Registers`]: crate::assembunny::Registers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RegisterId(u8);
+
+impl RegisterId {
+    pub const A: Self = Self(0);
+    pub const B: Self = Self(1);
+    pub const C: Self = Self(2);
+    pub const D: Self = Self(3);
+
+    /// The number of distinct register ids, one per letter.
+    pub const COUNT: usize = 26;
+
+    /// Returns `None` if `name` is not a lowercase ASCII letter.
+    pub fn new(name: char) -> Option<Self> {
+        name.is_ascii_lowercase().then(|| Self(name as u8 - b'a'))
+    }
+
+    /// Returns `None` if there is no letter for `index`.
+    pub fn from_index(index: usize) -> Option<Self> {
+        (index < Self::COUNT).then_some(Self(index as u8))
+    }
+
+    pub fn name(self) -> char {
+        char::from(b'a' + self.0)
+    }
+
+    pub fn index(self) -> usize {
+        self.0.into()
+    }
+}
+
+impl From<char> for RegisterId {
+    fn from(value: char) -> Self {
+        Self::new(value).unwrap_or_else(|| panic!("Cannot parse `{}` to `RegisterId`.", value))
+    }
+}
+
+impl Display for RegisterId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod register_id_tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(RegisterId::A, 'a')]
+    #[case(RegisterId::B, 'b')]
+    #[case(RegisterId::C, 'c')]
+    #[case(RegisterId::D, 'd')]
+    #[case(RegisterId(25), 'z')]
+    fn trait_from_from_test_valid_register_id(#[case] expected: RegisterId, #[case] actual: char) {
+        assert_eq!(expected, actual.into());
+    }
+
+    #[rstest]
+    #[case(0, Some(RegisterId::A))]
+    #[case(25, Some(RegisterId(25)))]
+    #[case(26, None)]
+    fn from_index_test(#[case] index: usize, #[case] expected: Option<RegisterId>) {
+        assert_eq!(expected, RegisterId::from_index(index));
+    }
+
+    #[rstest]
+    #[case(RegisterId::A, "a")]
+    #[case(RegisterId::D, "d")]
+    #[case(RegisterId(15), "p")]
+    fn trait_display_fmt_test(#[case] register_id: RegisterId, #[case] expected: &str) {
+        assert_eq!(expected, register_id.to_string());
+    }
+
+    #[rstest]
+    #[case('A')]
+    #[case('1')]
+    #[should_panic]
+    fn trait_from_from_test_invalid_register_id(#[case] name: char) {
+        let _: RegisterId = name.into();
+    }
+}
@@ -0,0 +1,174 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use super::{Argument, Assembunny, Instruction};
+
+/// An annotated listing of an [`Assembunny`] program. Every target of a `jnz` with a literal
+/// offset gets a label that replaces the offset, and every loop formed by a backward jump is
+/// marked next to its body: `/` at the start, `|` inside and `\` at the jump back. A jump to
+/// itself is marked with `@`.
+pub struct Listing<'a> {
+    assembunny: &'a Assembunny,
+}
+
+impl Assembunny {
+    pub fn listing(&self) -> Listing<'_> {
+        Listing { assembunny: self }
+    }
+}
+
+impl Listing<'_> {
+    /// Jumps with a literal offset whose target lies within the program or directly after it.
+    fn jumps(&self) -> Vec<(usize, usize)> {
+        self.assembunny
+            .iter()
+            .enumerate()
+            .filter_map(|(source, instruction)| match instruction {
+                Instruction::Jnz {
+                    jump_offset: Argument::Literal(offset),
+                    ..
+                } => (source as isize)
+                    .checked_add(*offset as isize)
+                    .and_then(|target| usize::try_from(target).ok())
+                    .filter(|target| *target <= self.assembunny.len())
+                    .map(|target| (source, target)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Assigns every loop to the leftmost column that is free for its whole body.
+    fn loop_columns(jumps: &[(usize, usize)]) -> Vec<Vec<(usize, usize)>> {
+        let mut loops = jumps
+            .iter()
+            .filter(|(source, target)| target <= source)
+            .map(|&(source, target)| (target, source))
+            .collect::<Vec<_>>();
+        loops.sort_unstable_by_key(|&(start, end)| (start, usize::MAX - end));
+
+        let mut columns: Vec<Vec<(usize, usize)>> = Vec::new();
+        for (start, end) in loops {
+            let free = columns
+                .iter_mut()
+                .find(|column| column.last().is_some_and(|&(_, last_end)| last_end < start));
+            match free {
+                Some(column) => column.push((start, end)),
+                None => columns.push(vec![(start, end)]),
+            }
+        }
+        columns
+    }
+
+    fn marker(column: &[(usize, usize)], ip: usize) -> char {
+        match column
+            .iter()
+            .find(|(start, end)| (*start..=*end).contains(&ip))
+        {
+            Some((start, end)) if start == end => '@',
+            Some((start, _)) if *start == ip => '/',
+            Some((_, end)) if *end == ip => '\\',
+            Some(_) => '|',
+            None => ' ',
+        }
+    }
+}
+
+impl Display for Listing<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let jumps = self.jumps();
+        let mut labels = BTreeMap::new();
+        for (_, target) in &jumps {
+            labels.insert(*target, String::new());
+        }
+        for (index, label) in labels.values_mut().enumerate() {
+            *label = format!("L{index}");
+        }
+        let targets = jumps.iter().copied().collect::<BTreeMap<_, _>>();
+        let columns = Self::loop_columns(&jumps);
+        let label_width = labels.values().map(|label| label.len() + 1).max();
+
+        for (ip, instruction) in self.assembunny.iter().enumerate() {
+            let label = labels
+                .get(&ip)
+                .map(|label| format!("{label}:"))
+                .unwrap_or_default();
+            let markers = columns
+                .iter()
+                .map(|column| Self::marker(column, ip))
+                .collect::<String>();
+            let text = match (instruction, targets.get(&ip)) {
+                (Instruction::Jnz { condition, .. }, Some(target)) => {
+                    format!("jnz {condition} {}", labels[target])
+                }
+                _ => instruction.to_string(),
+            };
+
+            write!(f, "{ip:>4}  ")?;
+            if let Some(label_width) = label_width {
+                write!(f, "{label:<label_width$} ")?;
+            }
+            if !columns.is_empty() {
+                write!(f, "{markers} ")?;
+            }
+            writeln!(f, "{text}")?;
+        }
+        if let Some(label) = labels.get(&self.assembunny.len()) {
+            writeln!(f, "{:>4}  {label}:", self.assembunny.len())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod listing_tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn trait_display_fmt_test() {
+        let assembunny = [
+            "cpy 2 a",
+            "jnz a 2",
+            "jnz 1 9",
+            "cpy 3 b",
+            "inc c",
+            "dec b",
+            "jnz b -2",
+            "dec a",
+            "jnz a -5",
+            "jnz 1 0",
+            "jnz 1 -20",
+        ]
+        .join("\n")
+        .parse::<Assembunny>()
+        .unwrap();
+        let expected = [
+            "   0         cpy 2 a",
+            "   1         jnz a L0",
+            "   2         jnz 1 L3",
+            "   3  L0: /  cpy 3 b",
+            "   4  L1: |/ inc c",
+            "   5      || dec b",
+            "   6      |\\ jnz b L1",
+            "   7      |  dec a",
+            "   8      \\  jnz a L0",
+            "   9  L2: @  jnz 1 L2",
+            "  10         jnz 1 -20",
+            "  11  L3:",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(expected, assembunny.listing().to_string());
+    }
+
+    #[test]
+    fn trait_display_fmt_test_without_jumps() {
+        let assembunny = "inc a\ndec b".parse::<Assembunny>().unwrap();
+
+        assert_eq!(
+            "   0  inc a\n   1  dec b\n",
+            assembunny.listing().to_string()
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use super::{
+    Argument::{Literal, Reference},
+    Assembunny, Instruction,
+    Instruction::{Add, Cpy, Dec, Inc, Jnz, Mul, Tgl},
+};
+
+impl Assembunny {
+    /// Replaces the jumps closing loops that only add or multiply registers with synthetic
+    /// [`Instruction::Add`] and [`Instruction::Mul`] instructions. They finish the loop at once if
+    /// its counters are positive and otherwise jump back like the replaced `jnz`, so the
+    /// original loop runs whenever it would not simply count down to zero.
+    ///
+    /// Programs containing `tgl` or jumps with a register offset are returned unchanged, because
+    /// their control flow cannot be known in advance.
+    pub fn optimized(&self) -> Self {
+        let Self(instructions) = self;
+        let mut optimized = instructions.clone();
+        if !has_static_control_flow(instructions) {
+            return Self(optimized);
+        }
+
+        let mut index = 0;
+        while index < instructions.len() {
+            let replacement = match_multiplication(&instructions[index..])
+                .map(Vec::from)
+                .or_else(|| match_addition(&instructions[index..]).map(Vec::from))
+                .filter(|replacement| !is_jumped_into(instructions, index, replacement.len()));
+
+            match replacement {
+                Some(replacement) => {
+                    optimized[index..index + replacement.len()].copy_from_slice(&replacement);
+                    index += replacement.len();
+                }
+                None => index += 1,
+            }
+        }
+
+        Self(optimized)
+    }
+}
+
+fn has_static_control_flow(instructions: &[Instruction]) -> bool {
+    instructions.iter().all(|instruction| {
+        !matches!(
+            instruction,
+            Tgl(_)
+                | Jnz {
+                    jump_offset: Reference(_),
+                    ..
+                }
+        )
+    })
+}
+
+/// Checks whether a jump outside of the `length` instructions at `start` targets one of them
+/// other than the first one.
+fn is_jumped_into(instructions: &[Instruction], start: usize, length: usize) -> bool {
+    let interior = start + 1..start + length;
+    instructions
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| !(start..start + length).contains(index))
+        .any(|(index, instruction)| match *instruction {
+            Jnz {
+                jump_offset: Literal(offset),
+                ..
+            } => (index as isize)
+                .checked_add(offset as isize)
+                .and_then(|target| usize::try_from(target).ok())
+                .is_some_and(|target| interior.contains(&target)),
+            _ => false,
+        })
+}
+
+/// `inc into / dec from / jnz from -2`, with `inc` and `dec` in either order.
+fn match_addition(instructions: &[Instruction]) -> Option<[Instruction; 3]> {
+    let window: [Instruction; 3] = instructions.get(..3)?.try_into().ok()?;
+    let (from, into) = match window {
+        [Inc(Reference(into)), Dec(Reference(from)), Jnz {
+            condition: Reference(condition),
+            jump_offset: Literal(Instruction::ADD_JUMP_OFFSET),
+        }]
+        | [Dec(Reference(from)), Inc(Reference(into)), Jnz {
+            condition: Reference(condition),
+            jump_offset: Literal(Instruction::ADD_JUMP_OFFSET),
+        }] if condition == from && from != into => (from, into),
+        _ => return None,
+    };
+
+    Some([window[0], window[1], Add { from, into }])
+}
+
+/// `cpy from scratch / inc into / dec scratch / jnz scratch -2 / dec times / jnz times -5`. The
+/// inner loop becomes an addition.
+fn match_multiplication(instructions: &[Instruction]) -> Option<[Instruction; 6]> {
+    let window: [Instruction; 6] = instructions.get(..6)?.try_into().ok()?;
+    let [Cpy {
+        from,
+        into: Reference(scratch),
+    }, Inc(Reference(into)), Dec(Reference(decremented_scratch)), Jnz {
+        condition: Reference(scratch_condition),
+        jump_offset: Literal(Instruction::ADD_JUMP_OFFSET),
+    }, Dec(Reference(times)), Jnz {
+        condition: Reference(times_condition),
+        jump_offset: Literal(Instruction::MUL_JUMP_OFFSET),
+    }] = window
+    else {
+        return None;
+    };
+
+    let registers = [scratch, into, times];
+    let are_distinct = scratch != into && scratch != times && into != times;
+    let is_from_independent = match from {
+        Reference(register_id) => !registers.contains(&register_id),
+        Literal(_) => true,
+    };
+    if decremented_scratch != scratch
+        || scratch_condition != scratch
+        || times_condition != times
+        || !are_distinct
+        || !is_from_independent
+    {
+        return None;
+    }
+
+    Some([
+        window[0],
+        window[1],
+        window[2],
+        Add {
+            from: scratch,
+            into,
+        },
+        window[4],
+        Mul {
+            from,
+            times,
+            into,
+            scratch,
+        },
+    ])
+}
+
+#[cfg(test)]
+mod optimization_tests {
+    use rstest::rstest;
+
+    use crate::assembunny::{
+        assembunny, Arithmetic, HaltReason, OverflowPolicy, RegisterId, Registers,
+        RuntimeEnvironment, Word, WordSize,
+    };
+
+    use super::*;
+
+    const FIBONACCI: [&str; 23] = [
+        "cpy 1 a", "cpy 1 b", "cpy 26 d", "jnz c 2", "jnz 1 5", "cpy 7 c", "inc d", "dec c",
+        "jnz c -2", "cpy a c", "inc a", "dec b", "jnz b -2", "cpy c b", "dec d", "jnz d -6",
+        "cpy 13 c", "cpy 14 d", "inc a", "dec d", "jnz d -2", "dec c", "jnz c -5",
+    ];
+
+    #[test]
+    fn optimized_test_addition() {
+        let expected = Assembunny(vec![
+            Instruction::Cpy {
+                from: Literal(3),
+                into: Reference(RegisterId::B),
+            },
+            Inc(Reference(RegisterId::A)),
+            Dec(Reference(RegisterId::B)),
+            Add {
+                from: RegisterId::B,
+                into: RegisterId::A,
+            },
+            Dec(Reference(RegisterId::A)),
+            Inc(Reference(RegisterId::C)),
+            Add {
+                from: RegisterId::A,
+                into: RegisterId::C,
+            },
+        ]);
+
+        let optimized = assembunny(&[
+            "cpy 3 b", "inc a", "dec b", "jnz b -2", "dec a", "inc c", "jnz a -2",
+        ])
+        .optimized();
+
+        assert_eq!(expected, optimized);
+    }
+
+    #[test]
+    fn optimized_test_multiplication() {
+        let expected = Assembunny(vec![
+            Cpy {
+                from: Reference(RegisterId::B),
+                into: Reference(RegisterId::C),
+            },
+            Inc(Reference(RegisterId::A)),
+            Dec(Reference(RegisterId::C)),
+            Add {
+                from: RegisterId::C,
+                into: RegisterId::A,
+            },
+            Dec(Reference(RegisterId::D)),
+            Mul {
+                from: Reference(RegisterId::B),
+                times: RegisterId::D,
+                into: RegisterId::A,
+                scratch: RegisterId::C,
+            },
+        ]);
+
+        let optimized =
+            assembunny(&["cpy b c", "inc a", "dec c", "jnz c -2", "dec d", "jnz d -5"]).optimized();
+
+        assert_eq!(expected, optimized);
+    }
+
+    #[rstest]
+    #[case(&["tgl c", "inc a", "dec b", "jnz b -2"])]
+    #[case(&["jnz 1 c", "inc a", "dec b", "jnz b -2"])]
+    #[case(&["jnz 1 2", "inc a", "dec b", "jnz b -2"])]
+    #[case(&["inc a", "dec b", "jnz a -2"])]
+    #[case(&["inc a", "dec a", "jnz a -2"])]
+    fn optimized_test_unchanged(#[case] program: &[&str]) {
+        let assembunny = assembunny(program);
+
+        assert_eq!(assembunny, assembunny.optimized());
+    }
+
+    #[rstest]
+    #[case(&["cpy b c", "inc a", "dec c", "jnz c -2", "dec d", "jnz d -5", "jnz 1 -2"])]
+    #[case(&["cpy a c", "inc a", "dec c", "jnz c -2", "dec d", "jnz d -5"])]
+    fn optimized_test_multiplication_falls_back_to_addition(#[case] program: &[&str]) {
+        let optimized = assembunny(program).optimized();
+
+        assert!(matches!(optimized[0], Cpy { .. }));
+        assert_eq!(
+            Add {
+                from: RegisterId::C,
+                into: RegisterId::A,
+            },
+            optimized[3]
+        );
+        assert_eq!(Dec(Reference(RegisterId::D)), optimized[4]);
+        assert!(matches!(optimized[5], Jnz { .. }));
+    }
+
+    #[rstest]
+    #[case(&FIBONACCI, [0, 0, 0, 0], Arithmetic::default())]
+    #[case(&FIBONACCI, [0, 0, 1, 0], Arithmetic::default())]
+    #[case(&["cpy 41 a", "inc a", "inc a", "dec a", "jnz a 2", "dec a"], [0, 0, 0, 0], Arithmetic::default())]
+    #[case(&["cpy b c", "inc a", "dec c", "jnz c -2", "dec d", "jnz d -5"], [2, 3, 0, 4], Arithmetic::default())]
+    #[case(&["cpy 7 c", "inc a", "dec c", "jnz c -2", "dec d", "jnz d -5"], [0, 0, 0, 6], Arithmetic::default())]
+    #[case(&["dec b", "inc a", "jnz b -2", "cpy a d", "inc c", "dec d", "jnz d -2"], [5, 9, 1, 0], Arithmetic::default())]
+    #[case(&["inc a", "dec b", "jnz b -2"], [2147483640, 10, 0, 0], Arithmetic::new(WordSize::Bits32, OverflowPolicy::Saturating))]
+    #[case(&["inc a", "dec b", "jnz b -2"], [2147483640, 10, 0, 0], Arithmetic::new(WordSize::Bits32, OverflowPolicy::Wrapping))]
+    #[case(&["cpy b c", "inc a", "dec c", "jnz c -2", "dec d", "jnz d -5"], [2147483640, 3, 0, 4], Arithmetic::new(WordSize::Bits32, OverflowPolicy::Saturating))]
+    #[case(&["cpy b c", "inc a", "dec c", "jnz c -2", "dec d", "jnz d -5"], [2147483640, 3, 0, 4], Arithmetic::new(WordSize::Bits32, OverflowPolicy::Wrapping))]
+    #[case(&["cpy b c", "inc a", "dec c", "jnz c -2", "dec d", "jnz d -5"], [2147483640, 3, 0, 4], Arithmetic::new(WordSize::Bits64, OverflowPolicy::Checked))]
+    fn optimized_test_differential(
+        #[case] program: &[&str],
+        #[case] registers: [Word; 4],
+        #[case] arithmetic: Arithmetic,
+    ) {
+        let run = |assembunny: Assembunny| {
+            let mut runtime_environment =
+                RuntimeEnvironment::load_assembunny_with_initialized_registers(
+                    assembunny,
+                    Registers::from(registers),
+                )
+                .unwrap()
+                .with_arithmetic(arithmetic)
+                .with_step_limit(100_000_000);
+            let outcome = runtime_environment.run_program();
+            assert_eq!(HaltReason::Halted, outcome.reason);
+            (outcome.steps, runtime_environment.registers())
+        };
+        let assembunny = assembunny(program);
+
+        let (unoptimized_steps, unoptimized_registers) = run(assembunny.clone());
+        let (optimized_steps, optimized_registers) = run(assembunny.optimized());
+
+        assert_eq!(unoptimized_registers, optimized_registers);
+        assert!(optimized_steps <= unoptimized_steps);
+    }
+}
@@ -0,0 +1,392 @@
+use super::{
+    Argument, Arithmetic, Assembunny, ExecutionOutcome, HaltReason, Instruction, RegisterId,
+    Registers, Word,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operand {
+    Literal(Word),
+    Register(RegisterId),
+}
+
+impl From<Argument> for Operand {
+    fn from(argument: Argument) -> Self {
+        match argument {
+            Argument::Literal(value) => Self::Literal(value),
+            Argument::Reference(register_id) => Self::Register(register_id),
+        }
+    }
+}
+
+/// An [`Instruction`] with pre-resolved operands. Jumps with a literal offset store their
+/// absolute target, instructions without any effect become [`Operation::Nop`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operation {
+    CpyLiteral {
+        value: Word,
+        into: RegisterId,
+    },
+    CpyRegister {
+        from: RegisterId,
+        into: RegisterId,
+    },
+    Inc(RegisterId),
+    Dec(RegisterId),
+    Jump(isize),
+    JumpIfNotZero {
+        condition: RegisterId,
+        target: isize,
+    },
+    JumpByRegister {
+        condition: Operand,
+        offset: RegisterId,
+    },
+    Out(Operand),
+    Add {
+        from: RegisterId,
+        into: RegisterId,
+        target: isize,
+    },
+    Mul {
+        from: Operand,
+        times: RegisterId,
+        into: RegisterId,
+        scratch: RegisterId,
+        target: isize,
+    },
+    Nop,
+}
+
+impl Operation {
+    fn compile(ip: isize, instruction: Instruction) -> Option<Self> {
+        let operation = match instruction {
+            Instruction::Cpy {
+                from,
+                into: Argument::Reference(into),
+            } => match from.into() {
+                Operand::Literal(value) => Self::CpyLiteral { value, into },
+                Operand::Register(from) => Self::CpyRegister { from, into },
+            },
+            Instruction::Inc(Argument::Reference(register_id)) => Self::Inc(register_id),
+            Instruction::Dec(Argument::Reference(register_id)) => Self::Dec(register_id),
+            Instruction::Jnz {
+                condition,
+                jump_offset: Argument::Literal(offset),
+            } => match condition.into() {
+                Operand::Literal(0) => Self::Nop,
+                Operand::Literal(_) => Self::Jump(ip.saturating_add(offset as isize)),
+                Operand::Register(condition) => Self::JumpIfNotZero {
+                    condition,
+                    target: ip.saturating_add(offset as isize),
+                },
+            },
+            Instruction::Jnz {
+                condition,
+                jump_offset: Argument::Reference(offset),
+            } => Self::JumpByRegister {
+                condition: condition.into(),
+                offset,
+            },
+            Instruction::Tgl(_) => return None,
+            Instruction::Out(value) => Self::Out(value.into()),
+            Instruction::Add { from, into } => Self::Add {
+                from,
+                into,
+                target: ip.saturating_add(Instruction::ADD_JUMP_OFFSET as isize),
+            },
+            Instruction::Mul {
+                from,
+                times,
+                into,
+                scratch,
+            } => Self::Mul {
+                from: from.into(),
+                times,
+                into,
+                scratch,
+                target: ip.saturating_add(Instruction::MUL_JUMP_OFFSET as isize),
+            },
+            Instruction::Cpy { .. }
+            | Instruction::Inc(_)
+            | Instruction::Dec(_)
+            | Instruction::Nop => Self::Nop,
+        };
+        Some(operation)
+    }
+}
+
+/// An [`Assembunny`] program compiled to a compact form that does not have to resolve operands
+/// or jump targets while running.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CompiledAssembunny {
+    operations: Vec<Operation>,
+}
+
+impl CompiledAssembunny {
+    /// Returns `None` if the program contains `tgl`, because it can modify the program.
+    pub(crate) fn compile(assembunny: &Assembunny) -> Option<Self> {
+        let operations = assembunny
+            .iter()
+            .enumerate()
+            .map(|(ip, instruction)| Operation::compile(ip as isize, *instruction))
+            .collect::<Option<_>>()?;
+        Some(Self { operations })
+    }
+
+    /// Has the same semantics as [`RuntimeEnvironment::run_program`], except that loops are only
+    /// looked for after jumps, since every loop contains one. A loop may therefore be detected
+    /// after a different number of steps.
+    ///
+    /// [`RuntimeEnvironment::run_program`]: super::RuntimeEnvironment::run_program
+    pub(crate) fn run<const N: usize>(
+        &self,
+        registers: &mut Registers<N>,
+        ip: &mut isize,
+        output: &mut Vec<Word>,
+        step_limit: Option<usize>,
+        arithmetic: Arithmetic,
+    ) -> ExecutionOutcome {
+        let (reason, steps, last_ip, last_registers) = self.execute(
+            *registers,
+            *ip,
+            output,
+            step_limit.unwrap_or(usize::MAX),
+            arithmetic,
+        );
+        *registers = last_registers;
+        *ip = last_ip;
+        ExecutionOutcome::new(reason, steps)
+    }
+
+    fn execute<const N: usize>(
+        &self,
+        mut registers: Registers<N>,
+        mut ip: isize,
+        output: &mut Vec<Word>,
+        step_limit: usize,
+        arithmetic: Arithmetic,
+    ) -> (HaltReason, usize, isize, Registers<N>) {
+        let mut steps = 0;
+        let mut jumps = 0_usize;
+        let mut saved_state = (ip, registers);
+        let mut next_save = 1;
+        let value = |registers: &Registers<N>, operand| match operand {
+            Operand::Literal(value) => value,
+            Operand::Register(register) => registers[register],
+        };
+
+        while let Some(&operation) = self.operations.get(ip as usize) {
+            if steps >= step_limit {
+                return (HaltReason::StepLimitReached, steps, ip, registers);
+            }
+            let target = match operation {
+                Operation::CpyLiteral { value, into } => {
+                    arithmetic.fit(value.into()).map(|value| {
+                        registers[into] = value;
+                        None
+                    })
+                }
+                Operation::CpyRegister { from, into } => {
+                    registers[into] = registers[from];
+                    Ok(None)
+                }
+                Operation::Inc(register) => arithmetic.add(registers[register], 1).map(|value| {
+                    registers[register] = value;
+                    None
+                }),
+                Operation::Dec(register) => arithmetic.sub(registers[register], 1).map(|value| {
+                    registers[register] = value;
+                    None
+                }),
+                Operation::Jump(target) => Ok(Some(target)),
+                Operation::JumpIfNotZero { condition, target } => {
+                    Ok((registers[condition] != 0).then_some(target))
+                }
+                Operation::JumpByRegister { condition, offset } => {
+                    Ok((value(&registers, condition) != 0)
+                        .then(|| ip.saturating_add(registers[offset] as isize)))
+                }
+                Operation::Out(operand) => {
+                    output.push(value(&registers, operand));
+                    Ok(None)
+                }
+                Operation::Add { from, into, target } => (registers[from] > 0)
+                    .then(|| arithmetic.add(registers[into], registers[from]))
+                    .transpose()
+                    .map(|sum| {
+                        if let Some(sum) = sum {
+                            registers[into] = sum;
+                            registers[from] = 0;
+                        }
+                        (registers[from] != 0).then_some(target)
+                    }),
+                Operation::Mul {
+                    from,
+                    times,
+                    into,
+                    scratch,
+                    target,
+                } => (value(&registers, from) > 0 && registers[times] > 0)
+                    .then(|| {
+                        arithmetic.mul_add(
+                            registers[into],
+                            value(&registers, from),
+                            registers[times],
+                        )
+                    })
+                    .transpose()
+                    .map(|sum| {
+                        if let Some(sum) = sum {
+                            registers[into] = sum;
+                            registers[times] = 0;
+                            registers[scratch] = 0;
+                        }
+                        (registers[times] != 0).then_some(target)
+                    }),
+                Operation::Nop => Ok(None),
+            };
+            let Ok(target) = target else {
+                return (HaltReason::Overflow, steps, ip, registers);
+            };
+            steps += 1;
+
+            let Some(target) = target else {
+                ip += 1;
+                continue;
+            };
+            ip = target;
+            jumps += 1;
+            if saved_state == (ip, registers) {
+                return (HaltReason::LoopDetected, steps, ip, registers);
+            }
+            if jumps == next_save {
+                saved_state = (ip, registers);
+                next_save *= 2;
+            }
+        }
+
+        (HaltReason::Halted, steps, ip, registers)
+    }
+}
+
+#[cfg(test)]
+mod compiled_tests {
+    use rstest::rstest;
+
+    use crate::assembunny::{assembunny, OverflowPolicy, RuntimeEnvironment, WordSize};
+
+    use super::*;
+
+    #[test]
+    fn compile_test() {
+        let expected = CompiledAssembunny {
+            operations: vec![
+                Operation::CpyLiteral {
+                    value: 4,
+                    into: RegisterId::B,
+                },
+                Operation::CpyRegister {
+                    from: RegisterId::B,
+                    into: RegisterId::C,
+                },
+                Operation::Inc(RegisterId::A),
+                Operation::Dec(RegisterId::D),
+                Operation::JumpIfNotZero {
+                    condition: RegisterId::C,
+                    target: 2,
+                },
+                Operation::Jump(-1),
+                Operation::Nop,
+                Operation::Nop,
+                Operation::JumpByRegister {
+                    condition: Operand::Literal(1),
+                    offset: RegisterId::D,
+                },
+                Operation::Out(Operand::Register(RegisterId::A)),
+            ],
+        };
+
+        let mut assembunny = assembunny(&[
+            "cpy 4 b", "cpy b c", "inc a", "dec d", "jnz c -2", "jnz 1 -6", "jnz 0 5", "jnz 1 d",
+            "out a",
+        ]);
+        assembunny.insert(7, Instruction::Inc(1.into()));
+
+        let compiled = CompiledAssembunny::compile(&assembunny);
+
+        assert_eq!(Some(expected), compiled);
+    }
+
+    #[test]
+    fn compile_test_tgl() {
+        assert_eq!(None, CompiledAssembunny::compile(&assembunny(&["tgl a"])));
+    }
+
+    #[rstest]
+    #[case(&["cpy 41 a", "inc a", "inc a", "dec a", "jnz a 2", "dec a"], [0, 0, 0, 0], Arithmetic::default())]
+    #[case(&["cpy 3 a", "dec a", "jnz a -1", "jnz 1 -10"], [0, 0, 0, 0], Arithmetic::default())]
+    #[case(&["cpy 2 b", "jnz 1 b", "inc a", "out b", "dec b", "jnz b -3"], [0, 0, 0, 0], Arithmetic::default())]
+    #[case(&["cpy 1 a", "jnz a 0"], [0, 0, 0, 0], Arithmetic::default())]
+    #[case(&["inc a", "jnz 1 -1"], [0, 0, 0, 0], Arithmetic::default())]
+    #[case(&["cpy b c", "inc a", "dec c", "jnz c -2", "dec d", "jnz d -5"], [2, 3, 0, 4], Arithmetic::default())]
+    #[case(&["cpy b c", "inc a", "dec c", "jnz c -2", "dec d", "jnz d -5"], [2, -3, 0, 4], Arithmetic::default())]
+    #[case(&["cpy -2 b", "inc a", "dec b", "jnz b -2"], [0, 0, 0, 0], Arithmetic::default())]
+    #[case(&["dec b", "inc a", "jnz b -2"], [0, 0, 0, 0], Arithmetic::default())]
+    #[case(&["inc a", "dec b", "jnz b -2"], [2147483640, 10, 0, 0], Arithmetic::new(WordSize::Bits32, OverflowPolicy::Saturating))]
+    #[case(&["inc a", "dec b", "jnz b -2"], [2147483640, 10, 0, 0], Arithmetic::new(WordSize::Bits32, OverflowPolicy::Wrapping))]
+    #[case(&["cpy b c", "inc a", "dec c", "jnz c -2", "dec d", "jnz d -5"], [2147483640, 3, 0, 4], Arithmetic::new(WordSize::Bits32, OverflowPolicy::Saturating))]
+    #[case(&["cpy b c", "inc a", "dec c", "jnz c -2", "dec d", "jnz d -5"], [2147483640, 3, 0, 4], Arithmetic::new(WordSize::Bits32, OverflowPolicy::Wrapping))]
+    #[case(&["cpy b c", "inc a", "dec c", "jnz c -2", "dec d", "jnz d -5"], [2147483640, 3, 0, 4], Arithmetic::new(WordSize::Bits64, OverflowPolicy::Checked))]
+    fn run_test_differential(
+        #[case] program: &[&str],
+        #[case] registers: [Word; 4],
+        #[case] arithmetic: Arithmetic,
+    ) {
+        let assembunny = assembunny(program);
+        let load = |assembunny: Assembunny| {
+            RuntimeEnvironment::load_assembunny_with_initialized_registers(
+                assembunny,
+                registers.into(),
+            )
+            .unwrap()
+            .with_arithmetic(arithmetic)
+            .with_step_limit(1000)
+        };
+        let mut interpreted = load(assembunny.clone());
+        let mut compiled = load(assembunny.clone());
+        let mut optimized = load(assembunny.optimized());
+
+        let outcome = interpreted.run_program();
+        let compiled_outcome = compiled.run_compiled();
+
+        assert_eq!(outcome.reason, compiled_outcome.reason);
+        if outcome.reason != HaltReason::LoopDetected {
+            assert_eq!(outcome, compiled_outcome);
+            assert_eq!(interpreted, compiled);
+        }
+        assert_eq!(outcome.reason, optimized.run_compiled().reason);
+        assert_eq!(interpreted.registers(), optimized.registers());
+    }
+
+    #[rstest]
+    #[case(OverflowPolicy::Checked)]
+    #[case(OverflowPolicy::Wrapping)]
+    #[case(OverflowPolicy::Saturating)]
+    fn run_test_mul_intermediate_overflow(#[case] overflow_policy: OverflowPolicy) {
+        let load = || {
+            RuntimeEnvironment::load_assembunny_with_initialized_registers(
+                assembunny(&["mul b d a c"]),
+                [-100_000, 65_536, 0, 32_769].into(),
+            )
+            .unwrap()
+            .with_arithmetic(Arithmetic::new(WordSize::Bits32, overflow_policy))
+        };
+        let mut interpreted = load();
+        let mut compiled = load();
+
+        assert_eq!(HaltReason::Halted, interpreted.run_program().reason);
+        assert_eq!(HaltReason::Halted, compiled.run_compiled().reason);
+        assert_eq!(2_147_449_184, interpreted.register_value(RegisterId::A));
+        assert_eq!(interpreted, compiled);
+    }
+}
@@ -0,0 +1,27 @@
+use snafu::prelude::*;
+
+use super::RegisterId;
+
+/// A program references a register that is not part of the register file it is loaded with.
+#[derive(Debug, Clone, PartialEq, Snafu)]
+#[snafu(
+    display(
+        "Instruction {ip} uses the register `{register_id}`, but there are only {count} registers."
+    ),
+    visibility(pub(super))
+)]
+pub struct MissingRegisterError {
+    ip: usize,
+    register_id: RegisterId,
+    count: usize,
+}
+
+impl MissingRegisterError {
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn register_id(&self) -> RegisterId {
+        self.register_id
+    }
+}
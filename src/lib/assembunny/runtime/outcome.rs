@@ -0,0 +1,30 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HaltReason {
+    /// The instruction pointer left the program.
+    Halted,
+    /// The step limit was exhausted before the program halted.
+    StepLimitReached,
+    /// The program reached a state it has already been in and will therefore never halt.
+    LoopDetected,
+    /// The breakpoint passed to [`run_until`](super::RuntimeEnvironment::run_until) was hit.
+    BreakpointReached,
+    /// An instruction overflowed with [`OverflowPolicy::Checked`](super::OverflowPolicy). It has
+    /// not been executed and the instruction pointer still points to it.
+    Overflow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionOutcome {
+    pub reason: HaltReason,
+    pub steps: usize,
+}
+
+impl ExecutionOutcome {
+    pub fn new(reason: HaltReason, steps: usize) -> Self {
+        Self { reason, steps }
+    }
+
+    pub fn has_halted(&self) -> bool {
+        self.reason == HaltReason::Halted
+    }
+}
@@ -0,0 +1,198 @@
+use std::ops::{Index, IndexMut};
+
+use super::{Arithmetic, Overflow, RegisterId, Word};
+
+/// The register file of a register machine: one [`Word`] for each of the `N` registers `a`, `b`,
+/// … in order. The default of four registers `a` to `d` is the one of Assembunny.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Registers<const N: usize = 4>([Word; N]);
+
+impl<const N: usize> Registers<N> {
+    pub fn new() -> Self {
+        Self([0; N])
+    }
+
+    pub fn contains(&self, register_id: RegisterId) -> bool {
+        register_id.index() < N
+    }
+
+    pub fn register_ids(&self) -> impl Iterator<Item = RegisterId> {
+        (0..N).map_while(RegisterId::from_index)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the register is not part of the register file, as do all other accessors.
+    pub fn register_value(&self, register_id: RegisterId) -> Word {
+        self[register_id]
+    }
+
+    pub fn register_value_mut(&mut self, register_id: RegisterId) -> &mut Word {
+        &mut self[register_id]
+    }
+
+    pub fn increment(
+        &mut self,
+        register_id: RegisterId,
+        arithmetic: Arithmetic,
+    ) -> Result<(), Overflow> {
+        self[register_id] = arithmetic.add(self[register_id], 1)?;
+        Ok(())
+    }
+
+    pub fn decrement(
+        &mut self,
+        register_id: RegisterId,
+        arithmetic: Arithmetic,
+    ) -> Result<(), Overflow> {
+        self[register_id] = arithmetic.sub(self[register_id], 1)?;
+        Ok(())
+    }
+}
+
+impl<const N: usize> Default for Registers<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Index<RegisterId> for Registers<N> {
+    type Output = Word;
+
+    fn index(&self, register_id: RegisterId) -> &Self::Output {
+        let Self(registers) = self;
+        &registers[register_id.index()]
+    }
+}
+
+impl<const N: usize> IndexMut<RegisterId> for Registers<N> {
+    fn index_mut(&mut self, register_id: RegisterId) -> &mut Self::Output {
+        let Self(registers) = self;
+        &mut registers[register_id.index()]
+    }
+}
+
+impl<const N: usize> From<[Word; N]> for Registers<N> {
+    fn from(register_values: [Word; N]) -> Self {
+        Self(register_values)
+    }
+}
+
+impl<const N: usize> From<Registers<N>> for [Word; N] {
+    fn from(Registers(register_values): Registers<N>) -> Self {
+        register_values
+    }
+}
+
+#[cfg(test)]
+mod registers_tests {
+    use crate::assembunny::{OverflowPolicy, WordSize};
+
+    use super::*;
+
+    #[test]
+    fn register_value_test() {
+        let registers = Registers([5, 0, 0, 0]);
+
+        assert_eq!(5, registers.register_value(RegisterId::A));
+    }
+
+    #[test]
+    #[should_panic]
+    fn register_value_test_missing_register() {
+        let registers = Registers([1, 2]);
+
+        registers.register_value(RegisterId::C);
+    }
+
+    #[test]
+    fn register_value_mut_test_unsigned() {
+        let mut registers = Registers::<4>::new();
+
+        *registers.register_value_mut(RegisterId::A) = 5;
+
+        assert_eq!([5, 0, 0, 0], registers.0);
+    }
+
+    #[test]
+    fn register_value_mut_test_signed() {
+        let mut registers = Registers::<4>::new();
+
+        *registers.register_value_mut(RegisterId::A) = -5;
+
+        assert_eq!([-5, 0, 0, 0], registers.0);
+    }
+
+    #[test]
+    fn increment_test() {
+        let mut registers = Registers::<4>::new();
+
+        registers
+            .increment(RegisterId::A, Arithmetic::default())
+            .unwrap();
+
+        assert_eq!([1, 0, 0, 0], registers.0);
+    }
+
+    #[test]
+    fn increment_test_overflow() {
+        let mut registers = Registers([i32::MAX.into(), 0, 0, 0]);
+
+        let result = registers.increment(RegisterId::A, Arithmetic::default());
+
+        assert_eq!(Err(Overflow), result);
+        assert_eq!([i32::MAX.into(), 0, 0, 0], registers.0);
+    }
+
+    #[test]
+    fn decrement_test() {
+        let mut registers = Registers::<4>::new();
+
+        registers
+            .decrement(RegisterId::A, Arithmetic::default())
+            .unwrap();
+
+        assert_eq!([-1, 0, 0, 0], registers.0);
+    }
+
+    #[test]
+    fn decrement_test_wrapping() {
+        let mut registers = Registers([i32::MIN.into(), 0, 0, 0]);
+        let arithmetic = Arithmetic::new(WordSize::Bits32, OverflowPolicy::Wrapping);
+
+        registers.decrement(RegisterId::A, arithmetic).unwrap();
+
+        assert_eq!([i32::MAX.into(), 0, 0, 0], registers.0);
+    }
+
+    #[test]
+    fn register_ids_test() {
+        let registers = Registers::<16>::new();
+
+        assert_eq!(
+            "abcdefghijklmnop",
+            registers
+                .register_ids()
+                .map(RegisterId::name)
+                .collect::<String>()
+        );
+        assert!(registers.contains('p'.into()));
+        assert!(!registers.contains('q'.into()));
+    }
+
+    #[test]
+    fn register_ids_test_more_registers_than_letters() {
+        assert_eq!(26, Registers::<30>::new().register_ids().count());
+    }
+
+    #[test]
+    fn trait_from_test_from() {
+        assert_eq!(Registers([1, 2, 3, 4]), [1, 2, 3, 4].into());
+        assert_eq!(Registers([1, 2]), [1, 2].into());
+    }
+
+    #[test]
+    fn trait_from_test_into_array() {
+        assert_eq!([1, 2, 3, 4], <[Word; 4]>::from(Registers([1, 2, 3, 4])));
+    }
+}
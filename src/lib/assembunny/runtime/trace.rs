@@ -0,0 +1,10 @@
+use super::{Instruction, Registers};
+
+/// A single executed instruction together with the register values around it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TraceEntry<const N: usize = 4> {
+    pub ip: isize,
+    pub instruction: Instruction,
+    pub before: Registers<N>,
+    pub after: Registers<N>,
+}
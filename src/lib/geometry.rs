@@ -0,0 +1,400 @@
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// An integer type that can be used as a coordinate.
+pub trait Number:
+    Copy
+    + Debug
+    + Default
+    + Eq
+    + Ord
+    + Hash
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+{
+    type Unsigned: Number;
+
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs_diff(self, other: Self) -> Self::Unsigned;
+}
+
+pub trait SignedNumber: Number + Neg<Output = Self> {
+    fn signum(self) -> Self;
+}
+
+pub trait UnsignedNumber: Number {
+    type Signed: SignedNumber;
+
+    fn checked_add_signed(self, rhs: Self::Signed) -> Option<Self>;
+}
+
+macro_rules! impl_numbers {
+    ($($signed:ty => $unsigned:ty),*) => {
+        $(
+            impl Number for $signed {
+                type Unsigned = $unsigned;
+
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs_diff(self, other: Self) -> Self::Unsigned {
+                    <$signed>::abs_diff(self, other)
+                }
+            }
+
+            impl SignedNumber for $signed {
+                fn signum(self) -> Self {
+                    <$signed>::signum(self)
+                }
+            }
+
+            impl Number for $unsigned {
+                type Unsigned = $unsigned;
+
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs_diff(self, other: Self) -> Self::Unsigned {
+                    <$unsigned>::abs_diff(self, other)
+                }
+            }
+
+            impl UnsignedNumber for $unsigned {
+                type Signed = $signed;
+
+                fn checked_add_signed(self, rhs: Self::Signed) -> Option<Self> {
+                    <$unsigned>::checked_add_signed(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_numbers!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Number> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self, other: Self) -> T::Unsigned {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T::Unsigned {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+impl<T: UnsignedNumber> Point<T> {
+    /// Moves the point by `vector`, or returns `None` if a coordinate would leave the range of `T`.
+    pub fn checked_shift(self, vector: Vector<T::Signed>) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add_signed(vector.dx)?,
+            y: self.y.checked_add_signed(vector.dy)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Vector<T> {
+    pub dx: T,
+    pub dy: T,
+}
+
+impl<T: Number> Vector<T> {
+    pub const fn new(dx: T, dy: T) -> Self {
+        Self { dx, dy }
+    }
+}
+
+impl<T: SignedNumber> Vector<T> {
+    /// The vector with both components clamped to -1, 0 or 1.
+    pub fn signum(self) -> Self {
+        Self::new(self.dx.signum(), self.dy.signum())
+    }
+
+    /// Rotates by 90 degrees counterclockwise, with y pointing up.
+    pub fn rotate_left(self) -> Self {
+        Self::new(-self.dy, self.dx)
+    }
+
+    /// Rotates by 90 degrees clockwise, with y pointing up.
+    pub fn rotate_right(self) -> Self {
+        Self::new(self.dy, -self.dx)
+    }
+}
+
+impl<T: Number> Add<Vector<T>> for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Vector<T>) -> Self::Output {
+        Self::new(self.x + rhs.dx, self.y + rhs.dy)
+    }
+}
+
+impl<T: Number> AddAssign<Vector<T>> for Point<T> {
+    fn add_assign(&mut self, rhs: Vector<T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Number> Sub<Vector<T>> for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vector<T>) -> Self::Output {
+        Self::new(self.x - rhs.dx, self.y - rhs.dy)
+    }
+}
+
+impl<T: Number> SubAssign<Vector<T>> for Point<T> {
+    fn sub_assign(&mut self, rhs: Vector<T>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Number> Sub for Point<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Number> Add for Vector<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.dx + rhs.dx, self.dy + rhs.dy)
+    }
+}
+
+impl<T: Number> Sub for Vector<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.dx - rhs.dx, self.dy - rhs.dy)
+    }
+}
+
+impl<T: Number> Mul<T> for Vector<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.dx * rhs, self.dy * rhs)
+    }
+}
+
+impl<T: SignedNumber> Neg for Vector<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.dx, -self.dy)
+    }
+}
+
+/// A compass direction, with north pointing towards increasing y.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::East => Self::North,
+            Self::South => Self::East,
+            Self::West => Self::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn_left().turn_left()
+    }
+
+    /// The vector of a single step in this direction.
+    pub fn vector<T: SignedNumber>(self) -> Vector<T> {
+        match self {
+            Self::North => Vector::new(T::ZERO, T::ONE),
+            Self::East => Vector::new(T::ONE, T::ZERO),
+            Self::South => Vector::new(T::ZERO, -T::ONE),
+            Self::West => Vector::new(-T::ONE, T::ZERO),
+        }
+    }
+}
+
+#[cfg(test)]
+mod geometry_tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(25, Point::new(-12, -2), Point::new(5, -10))]
+    #[case(25, Point::new(12, 2), Point::new(-5, 10))]
+    #[case(0, Point::new(3, 3), Point::new(3, 3))]
+    fn point_manhattan_test(
+        #[case] expected: u32,
+        #[case] from: Point<i32>,
+        #[case] to: Point<i32>,
+    ) {
+        assert_eq!(expected, from.manhattan(to));
+        assert_eq!(expected, to.manhattan(from));
+    }
+
+    #[rstest]
+    #[case(17, Point::new(-12, -2), Point::new(5, -10))]
+    #[case(1, Point::new(0, 0), Point::new(-1, 1))]
+    #[case(2, Point::new(0, 0), Point::new(2, 1))]
+    fn point_chebyshev_test(
+        #[case] expected: u32,
+        #[case] from: Point<i32>,
+        #[case] to: Point<i32>,
+    ) {
+        assert_eq!(expected, from.chebyshev(to));
+    }
+
+    #[rstest]
+    #[case(Some(Point::new(2, 0)), Point::new(3, 1), Vector::new(-1, -1))]
+    #[case(Some(Point::new(0, 255)), Point::new(0, 250), Vector::new(0, 5))]
+    #[case(None, Point::new(0, 1), Vector::new(-1, 0))]
+    #[case(None, Point::new(0, 251), Vector::new(0, 5))]
+    fn point_checked_shift_test(
+        #[case] expected: Option<Point<u8>>,
+        #[case] point: Point<u8>,
+        #[case] vector: Vector<i8>,
+    ) {
+        assert_eq!(expected, point.checked_shift(vector));
+    }
+
+    #[test]
+    fn point_operators_test() {
+        let mut point = Point::new(1, 2);
+
+        assert_eq!(Point::new(4, 6), point + Vector::new(3, 4));
+        assert_eq!(Point::new(-2, -2), point - Vector::new(3, 4));
+        assert_eq!(Vector::new(-2, 3), Point::new(-1, 5) - point);
+
+        point += Vector::new(1, 1);
+        assert_eq!(Point::new(2, 3), point);
+        point -= Vector::new(2, 3);
+        assert_eq!(Point::new(0, 0), point);
+    }
+
+    #[test]
+    fn vector_operators_test() {
+        let vector = Vector::new(1, -2);
+
+        assert_eq!(Vector::new(4, 2), vector + Vector::new(3, 4));
+        assert_eq!(Vector::new(-2, -6), vector - Vector::new(3, 4));
+        assert_eq!(Vector::new(3, -6), vector * 3);
+        assert_eq!(Vector::new(-1, 2), -vector);
+    }
+
+    #[rstest]
+    #[case(Vector::new(0, 1), Vector::new(0, 15))]
+    #[case(Vector::new(-1, 0), Vector::new(-15, 0))]
+    #[case(Vector::new(0, -1), Vector::new(0, -1))]
+    #[case(Vector::new(0, 0), Vector::new(0, 0))]
+    #[case(Vector::new(-1, 1), Vector::new(-15, 15))]
+    fn vector_signum_test(#[case] expected: Vector<i16>, #[case] vector: Vector<i16>) {
+        assert_eq!(expected, vector.signum());
+    }
+
+    #[rstest]
+    #[case(Direction::North)]
+    #[case(Direction::East)]
+    #[case(Direction::South)]
+    #[case(Direction::West)]
+    fn vector_rotate_test(#[case] direction: Direction) {
+        let vector = direction.vector::<i32>() * 3;
+
+        assert_eq!(
+            direction.turn_left().vector::<i32>() * 3,
+            vector.rotate_left()
+        );
+        assert_eq!(
+            direction.turn_right().vector::<i32>() * 3,
+            vector.rotate_right()
+        );
+    }
+
+    #[test]
+    fn direction_turn_left_test() {
+        let mut direction = Direction::North;
+
+        direction = direction.turn_left();
+        assert_eq!(Direction::West, direction);
+
+        direction = direction.turn_left();
+        assert_eq!(Direction::South, direction);
+
+        direction = direction.turn_left();
+        assert_eq!(Direction::East, direction);
+
+        direction = direction.turn_left();
+        assert_eq!(Direction::North, direction);
+    }
+
+    #[test]
+    fn direction_turn_right_test() {
+        let mut direction = Direction::North;
+
+        direction = direction.turn_right();
+        assert_eq!(Direction::East, direction);
+
+        direction = direction.turn_right();
+        assert_eq!(Direction::South, direction);
+
+        direction = direction.turn_right();
+        assert_eq!(Direction::West, direction);
+
+        direction = direction.turn_right();
+        assert_eq!(Direction::North, direction);
+    }
+
+    #[rstest]
+    #[case(Direction::South, Direction::North)]
+    #[case(Direction::West, Direction::East)]
+    fn direction_reverse_test(#[case] expected: Direction, #[case] direction: Direction) {
+        assert_eq!(expected, direction.reverse());
+        assert_eq!(direction, expected.reverse());
+    }
+
+    #[rstest]
+    #[case(Vector::new(0, 1), Direction::North)]
+    #[case(Vector::new(1, 0), Direction::East)]
+    #[case(Vector::new(0, -1), Direction::South)]
+    #[case(Vector::new(-1, 0), Direction::West)]
+    fn direction_vector_test(#[case] expected: Vector<i8>, #[case] direction: Direction) {
+        assert_eq!(expected, direction.vector());
+    }
+}
@@ -0,0 +1,559 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::geometry::{Point, Vector};
+
+/// A cell of a [`Grid`]: `x` is the column and `y` the row, both counted from the top left.
+pub type Position = Point<usize>;
+
+/// Which cells count as neighbors: the four orthogonal ones or also the four diagonal ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+impl Connectivity {
+    fn offsets(self) -> &'static [Vector<isize>] {
+        const OFFSETS: [Vector<isize>; 8] = [
+            Vector::new(0, -1),
+            Vector::new(1, 0),
+            Vector::new(0, 1),
+            Vector::new(-1, 0),
+            Vector::new(1, -1),
+            Vector::new(1, 1),
+            Vector::new(-1, 1),
+            Vector::new(-1, -1),
+        ];
+        match self {
+            Self::Four => &OFFSETS[..4],
+            Self::Eight => &OFFSETS,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum GridParseError<E> {
+    #[error("Row {row} has {actual} cells, but the first one has {expected}.")]
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("Could not parse the cell at column {}, row {}.", position.x, position.y)]
+    Cell {
+        position: Position,
+        #[source]
+        source: E,
+    },
+}
+
+/// A rectangular grid of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Position::new(x, y)))
+            .map(&mut cell)
+            .collect();
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses one row per line and every character of it with `cell`. All rows must have the
+    /// same number of characters.
+    pub fn parse<E>(
+        input: &str,
+        mut cell: impl FnMut(Position, char) -> Result<T, E>,
+    ) -> Result<Self, GridParseError<E>> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            let row_start = cells.len();
+            for (x, char) in line.chars().enumerate() {
+                let position = Position::new(x, y);
+                let value = cell(position, char)
+                    .map_err(|source| GridParseError::Cell { position, source })?;
+                cells.push(value);
+            }
+
+            let actual = cells.len() - row_start;
+            let expected = *width.get_or_insert(actual);
+            if actual != expected {
+                return Err(GridParseError::RaggedRow {
+                    row: y,
+                    expected,
+                    actual,
+                });
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width: width.unwrap_or_default(),
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Position::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> {
+        self.positions().zip(&mut self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).filter_map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells[x..].iter().step_by(self.width))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).filter_map(|x| self.column(x))
+    }
+
+    /// The neighbors of `position` that lie within the grid.
+    pub fn neighbors(
+        &self,
+        position: Position,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = Position> + '_ {
+        connectivity
+            .offsets()
+            .iter()
+            .filter_map(move |offset| position.checked_shift(*offset))
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// The `width` times `height` cells starting at `origin`, or `None` if they do not all lie
+    /// within the grid.
+    pub fn view(&self, origin: Position, width: usize, height: usize) -> Option<GridView<'_, T>> {
+        self.contains_rectangle(origin, width, height)
+            .then_some(GridView {
+                grid: self,
+                origin,
+                width,
+                height,
+            })
+    }
+
+    pub fn view_mut(
+        &mut self,
+        origin: Position,
+        width: usize,
+        height: usize,
+    ) -> Option<GridViewMut<'_, T>> {
+        self.contains_rectangle(origin, width, height)
+            .then_some(GridViewMut {
+                grid: self,
+                origin,
+                width,
+                height,
+            })
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(&mut f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then_some(position.y * self.width + position.x)
+    }
+
+    fn contains_rectangle(&self, origin: Position, width: usize, height: usize) -> bool {
+        origin
+            .x
+            .checked_add(width)
+            .is_some_and(|end| end <= self.width)
+            && origin
+                .y
+                .checked_add(height)
+                .is_some_and(|end| end <= self.height)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position)
+            .unwrap_or_else(|| panic!("Position `{position:?}` is out of bounds."))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("Position `{position:?}` is out of bounds."))
+    }
+}
+
+/// Renders every cell with its [`Display`] implementation, one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_rows(f, self.rows())
+    }
+}
+
+/// A rectangle of a [`Grid`]. Positions are relative to its top left corner.
+#[derive(Debug, Clone, Copy)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Position,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&'a T> {
+        (position.x < self.width && position.y < self.height)
+            .then(|| &self.grid[absolute(self.origin, position)])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let Self {
+            grid,
+            origin,
+            width,
+            height,
+        } = *self;
+        (origin.y..origin.y + height)
+            .filter_map(move |y| grid.row(y))
+            .map(move |row| &row[origin.x..origin.x + width])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &'a T)> {
+        self.rows().enumerate().flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(x, cell)| (Position::new(x, y), cell))
+        })
+    }
+}
+
+impl<T: Display> Display for GridView<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_rows(f, self.rows())
+    }
+}
+
+/// A mutable rectangle of a [`Grid`]. Positions are relative to its top left corner.
+#[derive(Debug)]
+pub struct GridViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    origin: Position,
+    width: usize,
+    height: usize,
+}
+
+impl<T> GridViewMut<'_, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        (position.x < self.width && position.y < self.height)
+            .then(|| &mut self.grid[absolute(self.origin, position)])
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        let Self {
+            origin,
+            width,
+            height,
+            ..
+        } = *self;
+        let grid_width = self.grid.width;
+        self.grid
+            .cells
+            .chunks_mut(grid_width.max(1))
+            .skip(origin.y)
+            .take(height)
+            .map(move |row| &mut row[origin.x..origin.x + width])
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> {
+        self.rows_mut().enumerate().flat_map(|(y, row)| {
+            row.iter_mut()
+                .enumerate()
+                .map(move |(x, cell)| (Position::new(x, y), cell))
+        })
+    }
+
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for row in self.rows_mut() {
+            row.fill(value.clone());
+        }
+    }
+}
+
+fn absolute(origin: Position, position: Position) -> Position {
+    Position::new(origin.x + position.x, origin.y + position.y)
+}
+
+fn write_rows<'a, T: Display + 'a>(
+    f: &mut std::fmt::Formatter<'_>,
+    rows: impl Iterator<Item = &'a [T]>,
+) -> std::fmt::Result {
+    for (index, row) in rows.enumerate() {
+        if index > 0 {
+            writeln!(f)?;
+        }
+        for cell in row {
+            write!(f, "{cell}")?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod grid_tests {
+    use std::convert::Infallible;
+
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef\nghi\njkl", |_, char| Ok::<_, Infallible>(char)).unwrap()
+    }
+
+    #[rstest]
+    fn parse_test(grid: Grid<char>) {
+        assert_eq!(3, grid.width());
+        assert_eq!(4, grid.height());
+        assert_eq!("abcdefghijkl".chars().collect::<Vec<_>>(), grid.cells);
+    }
+
+    #[test]
+    fn parse_test_empty() {
+        let grid = Grid::parse("", |_, char| Ok::<_, Infallible>(char)).unwrap();
+
+        assert_eq!((0, 0), (grid.width(), grid.height()));
+        assert_eq!("", grid.to_string());
+    }
+
+    #[test]
+    fn parse_test_ragged_row() {
+        let result = Grid::parse("ab\ncd\ne", |_, char| Ok::<_, Infallible>(char));
+
+        assert_eq!(
+            Err(GridParseError::RaggedRow {
+                row: 2,
+                expected: 2,
+                actual: 1
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn parse_test_cell() {
+        let result = Grid::parse("12\n3x", |_, char| char.to_digit(10).ok_or(char));
+
+        assert_eq!(
+            Err(GridParseError::Cell {
+                position: Position::new(1, 1),
+                source: 'x'
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn from_fn_test() {
+        let grid = Grid::from_fn(3, 2, |position| position.x * 10 + position.y);
+
+        assert_eq!(vec![0, 10, 20, 1, 11, 21], grid.cells);
+    }
+
+    #[rstest]
+    #[case(Position::new(0, 0), Some('a'))]
+    #[case(Position::new(2, 3), Some('l'))]
+    #[case(Position::new(3, 0), None)]
+    #[case(Position::new(0, 4), None)]
+    fn get_test(grid: Grid<char>, #[case] position: Position, #[case] expected: Option<char>) {
+        assert_eq!(expected, grid.get(position).copied());
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn trait_index_index_test_out_of_bounds(grid: Grid<char>) {
+        let _ = grid[Position::new(3, 3)];
+    }
+
+    #[rstest]
+    fn trait_index_mut_index_mut_test(mut grid: Grid<char>) {
+        grid[Position::new(1, 2)] = 'X';
+
+        assert_eq!("abc\ndef\ngXi\njkl", grid.to_string());
+    }
+
+    #[rstest]
+    fn rows_test(grid: Grid<char>) {
+        let rows = grid.rows().map(String::from_iter).collect::<Vec<_>>();
+
+        assert_eq!(vec!["abc", "def", "ghi", "jkl"], rows);
+        assert_eq!(None, grid.row(4));
+    }
+
+    #[rstest]
+    fn columns_test(grid: Grid<char>) {
+        let columns = grid.columns().map(String::from_iter).collect::<Vec<_>>();
+
+        assert_eq!(vec!["adgj", "behk", "cfil"], columns);
+        assert!(grid.column(3).is_none());
+    }
+
+    #[rstest]
+    #[case(Position::new(0, 0), Connectivity::Four, vec![(1, 0), (0, 1)])]
+    #[case(Position::new(1, 1), Connectivity::Four, vec![(1, 0), (2, 1), (1, 2), (0, 1)])]
+    #[case(Position::new(0, 0), Connectivity::Eight, vec![(1, 0), (0, 1), (1, 1)])]
+    #[case(
+        Position::new(2, 3),
+        Connectivity::Eight,
+        vec![(2, 2), (1, 3), (1, 2)]
+    )]
+    fn neighbors_test(
+        grid: Grid<char>,
+        #[case] position: Position,
+        #[case] connectivity: Connectivity,
+        #[case] expected: Vec<(usize, usize)>,
+    ) {
+        let expected = expected
+            .into_iter()
+            .map(|(x, y)| Position::new(x, y))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            expected,
+            grid.neighbors(position, connectivity).collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest]
+    fn view_test(grid: Grid<char>) {
+        let view = grid.view(Position::new(1, 1), 2, 3).unwrap();
+
+        assert_eq!("ef\nhi\nkl", view.to_string());
+        assert_eq!(Some(&'h'), view.get(Position::new(0, 1)));
+        assert_eq!(None, view.get(Position::new(2, 0)));
+        assert_eq!(
+            vec![(Position::new(0, 0), &'e'), (Position::new(1, 0), &'f')],
+            view.iter().take(2).collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest]
+    #[case(Position::new(1, 1), 3, 1)]
+    #[case(Position::new(0, 2), 1, 3)]
+    #[case(Position::new(usize::MAX, 0), 2, 1)]
+    fn view_test_out_of_bounds(
+        grid: Grid<char>,
+        #[case] origin: Position,
+        #[case] width: usize,
+        #[case] height: usize,
+    ) {
+        assert!(grid.view(origin, width, height).is_none());
+    }
+
+    #[rstest]
+    fn view_mut_test(mut grid: Grid<char>) {
+        let mut view = grid.view_mut(Position::new(0, 1), 2, 2).unwrap();
+        view.fill('.');
+        *view.get_mut(Position::new(1, 1)).unwrap() = '#';
+
+        assert_eq!("abc\n..f\n.#i\njkl", grid.to_string());
+    }
+
+    #[rstest]
+    fn map_test(grid: Grid<char>) {
+        let grid = grid.map(|char| char.to_ascii_uppercase());
+
+        assert_eq!("ABC\nDEF\nGHI\nJKL", grid.to_string());
+    }
+
+    #[test]
+    fn position_manhattan_test() {
+        assert_eq!(7, Position::new(1, 5).manhattan(Position::new(4, 1)));
+    }
+}
@@ -0,0 +1,218 @@
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    thread,
+};
+
+const DEFAULT_CHUNK_SIZE: usize = 4_096;
+
+/// Searches the smallest index whose MD5 digest of `<salt><index>` fulfills a predicate.
+///
+/// A fixed number of workers claim chunks of consecutive indices in increasing order. Once a
+/// match is found, no chunk beyond it is claimed and running chunks stop at it, so the result is
+/// always the smallest match no matter in which order the workers finish.
+#[derive(Debug, Clone, Copy)]
+pub struct Md5Search<'a> {
+    salt: &'a str,
+    workers: usize,
+    chunk_size: usize,
+    cancellation: Option<&'a AtomicBool>,
+}
+
+impl<'a> Md5Search<'a> {
+    /// Uses one worker per available CPU.
+    pub fn new(salt: &'a str) -> Self {
+        Self {
+            salt,
+            workers: thread::available_parallelism().map_or(1, NonZeroUsize::get),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            cancellation: None,
+        }
+    }
+
+    pub fn with_workers(mut self, workers: usize) -> Self {
+        self.workers = workers.max(1);
+        self
+    }
+
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Stops the search as soon as `cancellation` is set.
+    pub fn with_cancellation(mut self, cancellation: &'a AtomicBool) -> Self {
+        self.cancellation = Some(cancellation);
+        self
+    }
+
+    /// Returns `None` if the search was cancelled or no index from `start` on matches.
+    pub fn find_first(
+        &self,
+        start: usize,
+        predicate: impl Fn(&[u8; 16]) -> bool + Sync,
+    ) -> Option<usize> {
+        let mut salted = md5::Context::new();
+        salted.consume(self.salt);
+        let next_chunk = AtomicUsize::new(0);
+        let first_match = AtomicUsize::new(usize::MAX);
+        let is_cancelled = || {
+            self.cancellation
+                .is_some_and(|cancellation| cancellation.load(Ordering::Relaxed))
+        };
+
+        thread::scope(|scope| {
+            for _ in 0..self.workers {
+                scope.spawn(|| loop {
+                    let chunk = next_chunk.fetch_add(1, Ordering::Relaxed);
+                    let Some(chunk_start) = chunk
+                        .checked_mul(self.chunk_size)
+                        .and_then(|offset| start.checked_add(offset))
+                    else {
+                        return;
+                    };
+                    if chunk_start >= first_match.load(Ordering::Relaxed) || is_cancelled() {
+                        return;
+                    }
+
+                    let chunk_end = chunk_start.saturating_add(self.chunk_size);
+                    for index in chunk_start..chunk_end {
+                        if index >= first_match.load(Ordering::Relaxed) || is_cancelled() {
+                            break;
+                        }
+                        if predicate(&digest(&salted, index)) {
+                            first_match.fetch_min(index, Ordering::Relaxed);
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+
+        match first_match.into_inner() {
+            _ if is_cancelled() => None,
+            usize::MAX => None,
+            index => Some(index),
+        }
+    }
+}
+
+/// The digest of the salted context followed by the decimal digits of `index`.
+fn digest(salted: &md5::Context, index: usize) -> [u8; 16] {
+    let mut digits = [0; 20];
+    let mut position = digits.len();
+    let mut rest = index;
+    loop {
+        position -= 1;
+        digits[position] = b'0' + (rest % 10) as u8;
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+
+    let mut context = salted.clone();
+    context.consume(&digits[position..]);
+    context.compute().0
+}
+
+/// Whether the hexadecimal representation of `digest` starts with at least `count` zeros.
+pub fn has_leading_zero_nibbles(digest: &[u8; 16], count: usize) -> bool {
+    let (whole_bytes, half_byte) = (count / 2, count % 2 == 1);
+    whole_bytes <= digest.len()
+        && digest[..whole_bytes].iter().all(|byte| *byte == 0)
+        && (!half_byte || digest.get(whole_bytes).is_some_and(|byte| byte >> 4 == 0))
+}
+
+#[cfg(test)]
+mod hash_search_tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(0)]
+    #[case(7)]
+    #[case(1_234_567_890)]
+    #[case(usize::MAX)]
+    fn digest_test(#[case] index: usize) {
+        let mut salted = md5::Context::new();
+        salted.consume("abcdef");
+
+        assert_eq!(
+            md5::compute(format!("abcdef{index}")).0,
+            digest(&salted, index)
+        );
+    }
+
+    #[rstest]
+    #[case(true, 0)]
+    #[case(true, 4)]
+    #[case(true, 5)]
+    #[case(false, 6)]
+    #[case(false, 33)]
+    fn has_leading_zero_nibbles_test(#[case] expected: bool, #[case] count: usize) {
+        let mut digest = [0xff; 16];
+        digest[..2].fill(0);
+        digest[2] = 0x0a;
+
+        assert_eq!(expected, has_leading_zero_nibbles(&digest, count));
+    }
+
+    #[rstest]
+    #[case(1, 1)]
+    #[case(3, 1)]
+    #[case(4, 7)]
+    #[case(2, 1_000)]
+    fn md5_search_find_first_test(#[case] workers: usize, #[case] chunk_size: usize) {
+        let search = Md5Search::new("abcdef")
+            .with_workers(workers)
+            .with_chunk_size(chunk_size);
+        let expected = (0..)
+            .find(|index| md5::compute(format!("abcdef{index}"))[0] == 0)
+            .unwrap();
+
+        assert_eq!(
+            Some(expected),
+            search.find_first(0, |digest| digest[0] == 0)
+        );
+        assert_eq!(
+            Some(expected),
+            search.find_first(expected, |digest| digest[0] == 0)
+        );
+        assert_ne!(
+            Some(expected),
+            search.find_first(expected + 1, |digest| digest[0] == 0)
+        );
+    }
+
+    #[test]
+    fn md5_search_find_first_test_cancelled() {
+        let cancellation = AtomicBool::new(true);
+        let search = Md5Search::new("abcdef").with_cancellation(&cancellation);
+
+        assert_eq!(None, search.find_first(0, |_| false));
+    }
+
+    #[test]
+    fn md5_search_find_first_test_cancelled_while_running() {
+        let cancellation = AtomicBool::new(false);
+        let search = Md5Search::new("abcdef")
+            .with_workers(2)
+            .with_cancellation(&cancellation);
+
+        let result = search.find_first(0, |_| {
+            cancellation.store(true, Ordering::Relaxed);
+            false
+        });
+
+        assert_eq!(None, result);
+    }
+
+    #[test]
+    fn md5_search_find_first_test_exhausted() {
+        let search = Md5Search::new("abcdef").with_chunk_size(3);
+
+        assert_eq!(None, search.find_first(usize::MAX - 5, |_| false));
+    }
+}
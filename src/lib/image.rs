@@ -0,0 +1,248 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    aoc::{InvalidParameterError, Parameters},
+    grid::Grid,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+/// A picture of a grid that can be written as a binary Netpbm file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Image {
+    /// Black and white, with `true` as a white pixel. Written as PBM.
+    Bitmap(Grid<bool>),
+    /// Shades of gray from black at 0 to white at `max_value`. Written as PGM.
+    Graymap { pixels: Grid<u16>, max_value: u16 },
+    /// Written as PPM.
+    Pixmap(Grid<Rgb>),
+}
+
+impl Image {
+    /// Scales every value by the largest one, so the brightest pixel is white.
+    pub fn grayscale(values: &Grid<u16>) -> Self {
+        let max_value = values.iter().map(|(_, value)| *value).max().unwrap_or(0);
+        Self::Graymap {
+            pixels: values.clone(),
+            max_value: max_value.max(1),
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Bitmap(_) => "pbm",
+            Self::Graymap { .. } => "pgm",
+            Self::Pixmap(_) => "ppm",
+        }
+    }
+
+    pub fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        match self {
+            Self::Bitmap(pixels) => {
+                write!(writer, "P4\n{} {}\n", pixels.width(), pixels.height())?;
+                for row in pixels.rows() {
+                    // Every row starts at a new byte, and a set bit is a black pixel.
+                    for chunk in row.chunks(8) {
+                        let byte = chunk
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, white)| !**white)
+                            .fold(0u8, |byte, (bit, _)| byte | 0x80 >> bit);
+                        writer.write_all(&[byte])?;
+                    }
+                }
+            }
+            Self::Graymap { pixels, max_value } => {
+                write!(
+                    writer,
+                    "P5\n{} {}\n{}\n",
+                    pixels.width(),
+                    pixels.height(),
+                    max_value
+                )?;
+                for (_, value) in pixels.iter() {
+                    let value = (*value).min(*max_value);
+                    if *max_value < 256 {
+                        writer.write_all(&[value as u8])?;
+                    } else {
+                        writer.write_all(&value.to_be_bytes())?;
+                    }
+                }
+            }
+            Self::Pixmap(pixels) => {
+                write!(writer, "P6\n{} {}\n255\n", pixels.width(), pixels.height())?;
+                for (_, Rgb(red, green, blue)) in pixels.iter() {
+                    writer.write_all(&[*red, *green, *blue])?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the image to `path` with the extension of its format.
+    pub fn save(&self, path: &Path) -> io::Result<PathBuf> {
+        let path = path.with_extension(self.extension());
+        let mut writer = BufWriter::new(File::create(&path)?);
+        self.write(&mut writer)?;
+        writer.flush()?;
+        Ok(path)
+    }
+}
+
+/// Numbered images in one directory, for assembling an animation.
+#[derive(Debug)]
+pub struct FrameSequence {
+    directory: PathBuf,
+    next: usize,
+}
+
+impl FrameSequence {
+    pub fn create(directory: &Path) -> io::Result<Self> {
+        fs::create_dir_all(directory)?;
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            next: 0,
+        })
+    }
+
+    /// Saves the image as the next frame, e.g. `00042.pgm`.
+    pub fn push(&mut self, image: &Image) -> io::Result<PathBuf> {
+        let path = image.save(&self.directory.join(format!("{:05}", self.next)))?;
+        self.next += 1;
+        Ok(path)
+    }
+}
+
+/// Where a solution exports its images. Set the `images` parameter to a directory to export the
+/// final state, and additionally `frames = true` for the intermediate states.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageExport {
+    directory: PathBuf,
+    frames: bool,
+}
+
+impl ImageExport {
+    /// Returns `Ok(None)` if the `images` parameter is not set.
+    pub fn from_parameters(parameters: &Parameters) -> Result<Option<Self>, InvalidParameterError> {
+        let Some(directory) = parameters.get("images")? else {
+            return Ok(None);
+        };
+        Ok(Some(Self {
+            directory,
+            frames: parameters.get_or("frames", false)?,
+        }))
+    }
+
+    /// Saves the image as `<name>.<extension>` in the directory.
+    pub fn save(&self, name: &str, image: &Image) -> io::Result<PathBuf> {
+        fs::create_dir_all(&self.directory)?;
+        image.save(&self.directory.join(name))
+    }
+
+    /// A sequence in the subdirectory `name`, or `None` if frames are not requested.
+    pub fn frames(&self, name: &str) -> io::Result<Option<FrameSequence>> {
+        self.frames
+            .then(|| FrameSequence::create(&self.directory.join(name)))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod image_tests {
+    use std::convert::Infallible;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn written(image: &Image) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        image.write(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn image_write_test_bitmap() {
+        let pixels = Grid::parse("#........#\n.#.#.#.#.#", |_, char| {
+            Ok::<_, Infallible>(char == '#')
+        })
+        .unwrap();
+
+        let mut expected = b"P4\n10 2\n".to_vec();
+        expected.extend([0b0111_1111, 0b1000_0000, 0b1010_1010, 0b1000_0000]);
+
+        assert_eq!(expected, written(&Image::Bitmap(pixels)));
+    }
+
+    #[test]
+    fn image_write_test_graymap() {
+        let pixels = Grid::from_fn(3, 1, |position| position.x as u16 * 2);
+
+        let mut expected = b"P5\n3 1\n4\n".to_vec();
+        expected.extend([0, 2, 4]);
+
+        assert_eq!(expected, written(&Image::grayscale(&pixels)));
+    }
+
+    #[test]
+    fn image_write_test_graymap_wide() {
+        let pixels = Grid::from_fn(2, 1, |position| position.x as u16 * 300);
+
+        let mut expected = b"P5\n2 1\n300\n".to_vec();
+        expected.extend([0, 0, 1, 44]);
+
+        assert_eq!(expected, written(&Image::grayscale(&pixels)));
+    }
+
+    #[test]
+    fn image_write_test_pixmap() {
+        let pixels = Grid::from_fn(1, 2, |position| Rgb(position.y as u8, 2, 3));
+
+        let mut expected = b"P6\n1 2\n255\n".to_vec();
+        expected.extend([0, 2, 3, 1, 2, 3]);
+
+        assert_eq!(expected, written(&Image::Pixmap(pixels)));
+    }
+
+    #[test]
+    fn image_grayscale_test_black() {
+        let image = Image::grayscale(&Grid::filled(2, 2, 0));
+
+        assert!(matches!(image, Image::Graymap { max_value: 1, .. }));
+    }
+
+    #[test]
+    fn image_export_from_parameters_test() {
+        let parameters = Parameters::new().with("images", "out").with("frames", true);
+
+        assert_eq!(Ok(None), ImageExport::from_parameters(&Parameters::new()));
+        assert_eq!(
+            Ok(Some(ImageExport {
+                directory: PathBuf::from("out"),
+                frames: true
+            })),
+            ImageExport::from_parameters(&parameters)
+        );
+        assert!(ImageExport::from_parameters(&parameters.with("frames", "yes")).is_err());
+    }
+
+    #[test]
+    fn frame_sequence_push_test() {
+        let directory = std::env::temp_dir().join(format!("frames-{}", std::process::id()));
+        let image = Image::Bitmap(Grid::filled(1, 1, true));
+
+        let mut frames = FrameSequence::create(&directory).unwrap();
+        let first = frames.push(&image).unwrap();
+        let second = frames.push(&image).unwrap();
+
+        assert_eq!(directory.join("00000.pbm"), first);
+        assert_eq!(directory.join("00001.pbm"), second);
+        assert_eq!(b"P4\n1 1\n\0".to_vec(), fs::read(&second).unwrap());
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -1,2 +1,8 @@
 pub mod aoc;
+pub mod assembunny;
+pub mod geometry;
+pub mod grid;
+pub mod hash_search;
+pub mod image;
+pub mod search;
 pub mod std;
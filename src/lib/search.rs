@@ -0,0 +1,367 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap},
+    hash::Hash,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    pub cost: usize,
+    pub nodes: Vec<N>,
+}
+
+impl<N> Path<N> {
+    pub fn start(&self) -> &N {
+        self.nodes
+            .first()
+            .expect("Path contains at least one node.")
+    }
+
+    pub fn end(&self) -> &N {
+        self.nodes.last().expect("Path contains at least one node.")
+    }
+
+    pub fn steps(&self) -> usize {
+        self.nodes.len() - 1
+    }
+}
+
+/// The state of [`bfs_visiting`] before the nodes that are `depth` steps away from the starts are
+/// expanded.
+pub struct BfsLayer<'a, N> {
+    pub depth: usize,
+    pub frontier: &'a [N],
+    parents: &'a HashMap<N, Option<N>>,
+}
+
+impl<N: Eq + Hash> BfsLayer<'_, N> {
+    /// Every node that has been reached so far, including the frontier.
+    pub fn visited(&self) -> impl Iterator<Item = &N> {
+        self.parents.keys()
+    }
+}
+
+/// Finds the path with the fewest steps from any of `starts` to the first node that satisfies
+/// `is_goal`. Every step costs `1`. Returns `None` if no goal is reachable.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    is_goal: impl FnMut(&N) -> bool,
+    neighbors: impl FnMut(&N) -> I,
+) -> Option<Path<N>>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs_visiting(starts, is_goal, neighbors, |_| {})
+}
+
+/// Like [`bfs`], but passes every layer of the search to `visit` before it is expanded.
+pub fn bfs_visiting<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut is_goal: impl FnMut(&N) -> bool,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut visit: impl FnMut(&BfsLayer<N>),
+) -> Option<Path<N>>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents = HashMap::new();
+    let mut frontier = Vec::new();
+    for start in starts {
+        if parents.insert(start, None).is_none() {
+            frontier.push(start);
+        }
+    }
+
+    let mut depth = 0;
+    while !frontier.is_empty() {
+        visit(&BfsLayer {
+            depth,
+            frontier: &frontier,
+            parents: &parents,
+        });
+
+        let mut next = Vec::new();
+        for current in frontier {
+            if is_goal(&current) {
+                let nodes = reconstruct(current, |node| parents[node]);
+                return Some(Path {
+                    cost: nodes.len() - 1,
+                    nodes,
+                });
+            }
+
+            for neighbor in neighbors(&current) {
+                if let Entry::Vacant(entry) = parents.entry(neighbor) {
+                    entry.insert(Some(current));
+                    next.push(neighbor);
+                }
+            }
+        }
+        frontier = next;
+        depth += 1;
+    }
+
+    None
+}
+
+/// Finds the cheapest path from any of `starts` to the first node that satisfies `is_goal`.
+/// `neighbors` yields every reachable node together with the cost of the step.
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    is_goal: impl FnMut(&N) -> bool,
+    neighbors: impl FnMut(&N) -> I,
+) -> Option<Path<N>>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    a_star(starts, is_goal, neighbors, |_| 0)
+}
+
+/// Like [`dijkstra`], but nodes are explored in order of their cost plus `heuristic`. The
+/// heuristic must never overestimate the remaining cost, otherwise the path may not be the
+/// cheapest one.
+pub fn a_star<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut is_goal: impl FnMut(&N) -> bool,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+) -> Option<Path<N>>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut costs = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut nodes = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if costs.insert(start, 0).is_none() {
+            parents.insert(start, None);
+            heap.push(Reverse((heuristic(&start), 0, nodes.len())));
+            nodes.push(start);
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let current = nodes[index];
+        if cost > costs[&current] {
+            continue;
+        }
+        if is_goal(&current) {
+            return Some(Path {
+                cost,
+                nodes: reconstruct(current, |node| parents[node]),
+            });
+        }
+
+        for (neighbor, step) in neighbors(&current) {
+            let cost = cost + step;
+            if costs.get(&neighbor).is_some_and(|known| *known <= cost) {
+                continue;
+            }
+            costs.insert(neighbor, cost);
+            parents.insert(neighbor, Some(current));
+            heap.push(Reverse((cost + heuristic(&neighbor), cost, nodes.len())));
+            nodes.push(neighbor);
+        }
+    }
+
+    None
+}
+
+fn reconstruct<N: Copy>(end: N, mut parent: impl FnMut(&N) -> Option<N>) -> Vec<N> {
+    let mut nodes = vec![end];
+    while let Some(node) = parent(nodes.last().unwrap()) {
+        nodes.push(node);
+    }
+    nodes.reverse();
+    nodes
+}
+
+#[cfg(test)]
+mod search_tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const MAZE: [&str; 5] = [
+        "S..#....", //
+        ".#.#.##.", //
+        ".#...#..", //
+        ".####.#.", //
+        "......#E",
+    ];
+
+    type Node = (usize, usize);
+
+    fn find(byte: u8) -> Node {
+        MAZE.iter()
+            .enumerate()
+            .find_map(|(y, row)| Some((row.bytes().position(|b| b == byte)?, y)))
+            .unwrap()
+    }
+
+    fn open_neighbors(&(x, y): &Node) -> Vec<Node> {
+        [(0, 1), (0, -1), (1, 0), (-1, 0)]
+            .into_iter()
+            .filter_map(|(dx, dy)| {
+                let x = x.checked_add_signed(dx)?;
+                let y = y.checked_add_signed(dy)?;
+                (*MAZE.get(y)?.as_bytes().get(x)? != b'#').then_some((x, y))
+            })
+            .collect()
+    }
+
+    fn weighted_neighbors(node: &Node) -> Vec<(Node, usize)> {
+        open_neighbors(node)
+            .into_iter()
+            .map(|neighbor| (neighbor, 1))
+            .collect()
+    }
+
+    fn manhattan(&(x, y): &Node) -> usize {
+        let (end_x, end_y) = find(b'E');
+        x.abs_diff(end_x) + y.abs_diff(end_y)
+    }
+
+    fn assert_valid(path: &Path<Node>) {
+        assert_eq!(find(b'S'), *path.start());
+        assert_eq!(find(b'E'), *path.end());
+        for window in path.nodes.windows(2) {
+            assert!(open_neighbors(&window[0]).contains(&window[1]));
+        }
+    }
+
+    #[test]
+    fn bfs_test() {
+        let end = find(b'E');
+
+        let path = bfs([find(b'S')], |node| *node == end, open_neighbors).unwrap();
+
+        assert_eq!(15, path.cost);
+        assert_eq!(15, path.steps());
+        assert_valid(&path);
+    }
+
+    #[test]
+    fn bfs_visiting_test() {
+        let mut layers = Vec::new();
+
+        let path = bfs_visiting(
+            [0],
+            |node| *node == 3,
+            |node: &u8| [node + 1, node + 2],
+            |layer| {
+                let mut visited = layer.visited().copied().collect::<Vec<_>>();
+                visited.sort_unstable();
+                layers.push((layer.depth, layer.frontier.to_vec(), visited));
+            },
+        )
+        .unwrap();
+
+        assert_eq!(vec![0, 1, 3], path.nodes);
+        assert_eq!(
+            vec![
+                (0, vec![0], vec![0]),
+                (1, vec![1, 2], vec![0, 1, 2]),
+                (2, vec![3, 4], vec![0, 1, 2, 3, 4]),
+            ],
+            layers
+        );
+    }
+
+    #[test]
+    fn dijkstra_test() {
+        let end = find(b'E');
+
+        let path = dijkstra([find(b'S')], |node| *node == end, weighted_neighbors).unwrap();
+
+        assert_eq!(15, path.cost);
+        assert_valid(&path);
+    }
+
+    #[test]
+    fn a_star_test() {
+        let end = find(b'E');
+
+        let path = a_star(
+            [find(b'S')],
+            |node| *node == end,
+            weighted_neighbors,
+            manhattan,
+        )
+        .unwrap();
+
+        assert_eq!(15, path.cost);
+        assert_valid(&path);
+    }
+
+    #[test]
+    fn dijkstra_test_weighted() {
+        // 0 -> 1 -> 3 costs 1 + 5, 0 -> 2 -> 3 costs 4 + 1.
+        let edges = |node: &u8| match node {
+            0 => vec![(1, 1), (2, 4)],
+            1 => vec![(3, 5)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        };
+
+        let path = dijkstra([0], |node| *node == 3, edges).unwrap();
+
+        assert_eq!(
+            Path {
+                cost: 5,
+                nodes: vec![0, 2, 3]
+            },
+            path
+        );
+    }
+
+    #[test]
+    fn bfs_test_multiple_starts() {
+        let path = bfs([5, 0, 8], |node| *node == 10, |node: &u8| [node + 1]).unwrap();
+
+        assert_eq!(vec![8, 9, 10], path.nodes);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1)]
+    fn search_test_start_is_goal(#[case] algorithm: u8) {
+        let path = match algorithm {
+            0 => bfs([7], |node| *node == 7, |_: &u8| []),
+            _ => dijkstra([7], |node| *node == 7, |_: &u8| []),
+        };
+
+        assert_eq!(
+            Some(Path {
+                cost: 0,
+                nodes: vec![7]
+            }),
+            path
+        );
+    }
+
+    #[test]
+    fn search_test_unreachable() {
+        let cycle = |node: &u8| [(node + 1) % 4];
+
+        assert_eq!(None, bfs([0], |node| *node == 9, cycle));
+        assert_eq!(
+            None,
+            dijkstra([0], |node| *node == 9, |node| cycle(node).map(|n| (n, 1)))
+        );
+        assert_eq!(
+            None,
+            a_star(
+                [0],
+                |node| *node == 9,
+                |node| cycle(node).map(|n| (n, 1)),
+                |_| 0
+            )
+        );
+    }
+}